@@ -0,0 +1,173 @@
+// Day 1: Sonar Sweep
+// https://adventofcode.com/2021/day/1
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::collections::VecDeque;
+
+use aoc_common::{parse_value, read_and_parse, Error, InputSource, ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u64>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_depths(input)
+    }
+
+    fn summarise_input(depths: &Self::Input) -> String {
+        format!("{} depths", depths.len())
+    }
+
+    fn part_one(depths: &Self::Input) -> Self::PartOne {
+        count_window_increases(depths.iter().copied(), 1)
+    }
+
+    fn part_two(depths: &Self::Input) -> Self::PartTwo {
+        count_window_increases(depths.iter().copied(), 3)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid depth.
+pub fn read_depths(input: impl Into<InputSource>) -> Result<Vec<u64>, Error> {
+    read_and_parse(input, parse_depths)
+}
+
+/// # Errors
+///
+/// Returns an error if any line is not a valid depth.
+pub fn parse_depths(input: &str) -> Result<Vec<u64>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_value(input, line, "a depth"))
+        .collect()
+}
+
+/// Reads the depths one line at a time, so that inputs too large to fit in memory can be
+/// processed (such as with `count_window_increases`).
+///
+/// # Errors
+///
+/// Returns an error if the input cannot be opened. Errors reading or parsing each depth are
+/// returned by the iterator instead.
+pub fn stream_depths(
+    input: impl Into<InputSource>,
+) -> Result<impl Iterator<Item = Result<u64, Error>>, Error> {
+    input
+        .into()
+        .read_and_parse_lines(|line| parse_value(line, line, "a depth"))
+}
+
+/// Counts how many times the sum of a window of `window_size` consecutive depths increases,
+/// as the window slides along the depths one at a time.
+///
+/// The depths are processed in a single pass, only storing the current window.
+#[must_use]
+pub fn count_window_increases(depths: impl IntoIterator<Item = u64>, window_size: usize) -> usize {
+    count_window_increases_for_each(depths, &[window_size])[0]
+}
+
+/// Counts the window increases (as with `count_window_increases`) for each of several window
+/// sizes at once, so that the depths only need to be read once.
+///
+/// The depths are processed in a single pass, only storing the largest window.
+#[must_use]
+pub fn count_window_increases_for_each(
+    depths: impl IntoIterator<Item = u64>,
+    window_sizes: &[usize],
+) -> Vec<usize> {
+    let largest_window_size = window_sizes.iter().copied().max().unwrap_or(0);
+    // We need to compare the sum of measurements "ABC" with "BCD". Since "BC" overlaps
+    // between the two, we can instead compare only the depth leaving the window ("A") with
+    // the depth entering it ("D"), without having to calculate the sums at all. The sum of
+    // every empty window is zero, so never increases. The window size may be far larger
+    // than the input, so the stored depths are left to grow as needed, not preallocated.
+    let mut previous_depths = VecDeque::new();
+    let mut increases = vec![0; window_sizes.len()];
+    for depth in depths {
+        for (&window_size, count) in window_sizes.iter().zip(&mut increases) {
+            if window_size > 0
+                && previous_depths.len() >= window_size
+                && depth > previous_depths[previous_depths.len() - window_size]
+            {
+                *count += 1;
+            }
+        }
+        if largest_window_size > 0 {
+            if previous_depths.len() == largest_window_size {
+                previous_depths.pop_front();
+            }
+            previous_depths.push_back(depth);
+        }
+    }
+    increases
+}
+
+/// Counts the window increases for each of the window sizes (as with
+/// `count_window_increases_for_each`), whilst streaming the depths from the input.
+///
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid depth.
+pub fn count_window_increases_in(
+    input: impl Into<InputSource>,
+    window_sizes: &[usize],
+) -> Result<Vec<usize>, Error> {
+    itertools::process_results(stream_depths(input)?, |depths| {
+        count_window_increases_for_each(depths, window_sizes)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let depths = read_depths(example_file()).unwrap();
+        assert_eq!(count_window_increases(depths, 1), 7);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let depths = read_depths(example_file()).unwrap();
+        assert_eq!(count_window_increases(depths, 3), 5);
+    }
+
+    #[test]
+    fn other_window_sizes() {
+        let depths = read_depths(example_file()).unwrap();
+        assert_eq!(count_window_increases(depths.iter().copied(), 4), 6);
+        assert_eq!(count_window_increases(depths.iter().copied(), 0), 0);
+        assert_eq!(count_window_increases(depths.iter().copied(), 10), 0);
+        assert_eq!(
+            count_window_increases(depths.iter().copied(), usize::MAX),
+            0
+        );
+    }
+
+    #[test]
+    fn streaming_example() {
+        assert_eq!(
+            count_window_increases_in(example_file(), &[1, 3]).unwrap(),
+            vec![7, 5]
+        );
+        assert_eq!(
+            count_window_increases_in(example_file(), &[4, 0, 10, 1]).unwrap(),
+            vec![6, 0, 0, 7]
+        );
+    }
+}
@@ -0,0 +1,213 @@
+// Day 2: Dive!
+// https://adventofcode.com/2021/day/1
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::str::FromStr;
+
+use aoc_common::{
+    parse_lines, parse_value, read_and_parse, Error, InputSource, ParseError, Solution,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Command>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_commands(input)
+    }
+
+    fn summarise_input(commands: &Self::Input) -> String {
+        format!("{} commands", commands.len())
+    }
+
+    fn part_one(commands: &Self::Input) -> Self::PartOne {
+        let position = calculate_position(commands);
+        position.horizontal * position.depth
+    }
+
+    fn part_two(commands: &Self::Input) -> Self::PartTwo {
+        let position = calculate_position_with_aim(commands);
+        position.horizontal * position.depth
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid command.
+pub fn read_commands(input: impl Into<InputSource>) -> Result<Vec<Command>, Error> {
+    read_and_parse(input, parse_commands)
+}
+
+/// # Errors
+///
+/// Returns an error if any of the commands are invalid.
+pub fn parse_commands(input: &str) -> Result<Vec<Command>, ParseError> {
+    parse_lines(input)
+}
+
+#[must_use]
+pub fn calculate_position(commands: &[Command]) -> Position {
+    let mut position = Position::new();
+    for command in commands {
+        position.apply_command(command);
+    }
+    position
+}
+
+#[must_use]
+pub fn calculate_position_with_aim(commands: &[Command]) -> PositionWithAim {
+    let mut position = PositionWithAim::new();
+    for command in commands {
+        position.apply_command(command);
+    }
+    position
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Position {
+    pub horizontal: u64,
+    pub depth: u64,
+}
+
+impl Position {
+    fn new() -> Self {
+        Self {
+            horizontal: 0,
+            depth: 0,
+        }
+    }
+
+    pub fn apply_command(&mut self, command: &Command) {
+        match command.direction {
+            Direction::Forward => self.horizontal += command.amount,
+            // Since this is depth, `Up` decreases the value, rather than the inverse.
+            Direction::Up => self.depth -= command.amount,
+            Direction::Down => self.depth += command.amount,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PositionWithAim {
+    pub horizontal: u64,
+    pub depth: u64,
+    // Higher aim means aiming deeper.
+    pub aim: u64,
+}
+
+impl PositionWithAim {
+    fn new() -> Self {
+        Self {
+            horizontal: 0,
+            depth: 0,
+            aim: 0,
+        }
+    }
+
+    pub fn apply_command(&mut self, command: &Command) {
+        match command.direction {
+            Direction::Forward => {
+                self.horizontal += command.amount;
+                self.depth += self.aim * command.amount;
+            }
+            // Aim shallower.
+            Direction::Up => self.aim -= command.amount,
+            // Aim deeper.
+            Direction::Down => self.aim += command.amount,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Command {
+    pub direction: Direction,
+    pub amount: u64,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        // eg: "forward 5"
+        if let Some((direction, amount)) = value.split_once(' ') {
+            Ok(Command {
+                direction: direction
+                    .parse::<Direction>()
+                    .map_err(|error| error.within(value, direction))?,
+                amount: parse_value(value, amount, "an amount")?,
+            })
+        } else {
+            Err(ParseError::at(
+                value,
+                value,
+                "a direction and amount separated by a space",
+            ))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    Forward,
+    Up,
+    Down,
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "forward" => Ok(Direction::Forward),
+            "up" => Ok(Direction::Up),
+            "down" => Ok(Direction::Down),
+            _ => Err(ParseError::at(value, value, "forward, up or down")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let commands = read_commands(example_file()).unwrap();
+        let position = calculate_position(&commands);
+        assert_eq!(position.horizontal, 15);
+        assert_eq!(position.depth, 10);
+        assert_eq!(position.horizontal * position.depth, 150);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let commands = read_commands(example_file()).unwrap();
+        let position = calculate_position_with_aim(&commands);
+        assert_eq!(position.horizontal, 15);
+        assert_eq!(position.depth, 60);
+        assert_eq!(position.horizontal * position.depth, 900);
+    }
+
+    #[test]
+    fn parse_commands_invalid() {
+        let error = parse_commands("forward 5\ndown x").unwrap_err();
+        assert_eq!(error.to_string(), r#"2:6: expected an amount, found "x""#);
+        let error = parse_commands("forward 5\nbackward 2").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"2:1: expected forward, up or down, found "backward""#
+        );
+    }
+}
@@ -0,0 +1,210 @@
+// Day 3: Binary Diagnostic
+// https://adventofcode.com/2021/day/3
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use aoc_common::{read_and_parse, Error, InputSource, ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = DiagnosticReport;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_diagnostic_report(input)
+    }
+
+    fn summarise_input(report: &Self::Input) -> String {
+        format!("{} {}-bit numbers", report.numbers.len(), report.bit_width)
+    }
+
+    fn part_one(report: &Self::Input) -> Self::PartOne {
+        power_consumption(report)
+    }
+
+    fn part_two(report: &Self::Input) -> Self::PartTwo {
+        life_support_rating(report)
+    }
+}
+
+#[derive(Debug)]
+pub struct DiagnosticReport {
+    // The number of bits in every number, which is determined from the input rather than
+    // assumed, so that reports of any width up to 64 bits are supported.
+    pub bit_width: usize,
+    pub numbers: Vec<u64>,
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or is not a valid diagnostic report.
+pub fn read_diagnostic_report(input: impl Into<InputSource>) -> Result<DiagnosticReport, Error> {
+    read_and_parse(input, parse_diagnostic_report)
+}
+
+/// Parses a diagnostic report consisting of one binary number per line (eg "00100"),
+/// all of which must have the same number of bits.
+///
+/// # Errors
+///
+/// Returns an error if the report is empty, or any line is not a binary number with the
+/// same number of bits as the first line.
+pub fn parse_diagnostic_report(input: &str) -> Result<DiagnosticReport, ParseError> {
+    let bit_width = input.lines().next().map_or(0, str::len);
+    if !(1..=64).contains(&bit_width) {
+        let first_line = input.lines().next().unwrap_or(input);
+        return Err(ParseError::at(
+            input,
+            first_line,
+            "a binary number of 1 to 64 bits",
+        ));
+    }
+
+    let numbers = input
+        .lines()
+        .map(|line| {
+            let invalid_digit = line
+                .char_indices()
+                .find(|(_, char)| !matches!(char, '0' | '1'));
+            if let Some((index, char)) = invalid_digit {
+                let text = &line[index..index + char.len_utf8()];
+                return Err(ParseError::at(input, text, "a binary digit"));
+            }
+            if line.len() != bit_width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("a binary number of {bit_width} bits"),
+                ));
+            }
+            Ok(line
+                .bytes()
+                .fold(0, |number, digit| (number << 1) | u64::from(digit - b'0')))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(DiagnosticReport { bit_width, numbers })
+}
+
+#[must_use]
+pub fn power_consumption(report: &DiagnosticReport) -> u128 {
+    let mut gamma_rate = 0;
+    for bit in (0..report.bit_width).rev() {
+        gamma_rate <<= 1;
+        if most_common_bit(&report.numbers, bit) {
+            gamma_rate |= 1;
+        }
+    }
+    // The epsilon rate uses the least common bits, so is the inverse of the gamma rate,
+    // within the width of the numbers in the report.
+    let epsilon_rate = !gamma_rate & bit_mask(report.bit_width);
+    // The product of two 64-bit numbers may not fit in a u64.
+    u128::from(gamma_rate) * u128::from(epsilon_rate)
+}
+
+/// # Panics
+///
+/// Panics if the report contains no numbers.
+#[must_use]
+pub fn life_support_rating(report: &DiagnosticReport) -> u128 {
+    let oxygen_generator_rating = filter_by_bit_criteria(report, true);
+    let co2_scrubber_rating = filter_by_bit_criteria(report, false);
+    u128::from(oxygen_generator_rating) * u128::from(co2_scrubber_rating)
+}
+
+// Repeatedly filters the numbers by the most (or least) common value of each bit in turn,
+// starting with the most significant, until only one number remains.
+fn filter_by_bit_criteria(report: &DiagnosticReport, keep_most_common: bool) -> u64 {
+    let mut numbers = report.numbers.clone();
+    for bit in (0..report.bit_width).rev() {
+        if numbers.len() <= 1 {
+            break;
+        }
+        // Ties are resolved in favour of 1 for the most common, and 0 for the least common.
+        let bit_to_keep = most_common_bit(&numbers, bit) == keep_most_common;
+        let remaining: Vec<u64> = numbers
+            .iter()
+            .copied()
+            .filter(|number| bit_is_set(*number, bit) == bit_to_keep)
+            .collect();
+        // If every number has the same value for this bit then there's no least common value,
+        // so all of the numbers are kept rather than discarding them all.
+        if !remaining.is_empty() {
+            numbers = remaining;
+        }
+    }
+    *numbers
+        .first()
+        .expect("Report must contain at least one number")
+}
+
+// Returns true if 1 is at least as common as 0 for the given bit.
+fn most_common_bit(numbers: &[u64], bit: usize) -> bool {
+    let ones = numbers
+        .iter()
+        .filter(|number| bit_is_set(**number, bit))
+        .count();
+    ones * 2 >= numbers.len()
+}
+
+fn bit_is_set(number: u64, bit: usize) -> bool {
+    number & (1 << bit) != 0
+}
+
+fn bit_mask(bit_width: usize) -> u64 {
+    u64::MAX >> (64 - bit_width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let report = read_diagnostic_report(example_file()).unwrap();
+        assert_eq!(report.bit_width, 5);
+        assert_eq!(power_consumption(&report), 198);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let report = read_diagnostic_report(example_file()).unwrap();
+        assert_eq!(filter_by_bit_criteria(&report, true), 23);
+        assert_eq!(filter_by_bit_criteria(&report, false), 10);
+        assert_eq!(life_support_rating(&report), 230);
+    }
+
+    #[test]
+    fn other_bit_widths() {
+        // Gamma rate is 101 and epsilon rate is 010.
+        let report = parse_diagnostic_report("101\n100\n111\n001").unwrap();
+        assert_eq!(power_consumption(&report), 5 * 2);
+        let report = parse_diagnostic_report(&"1".repeat(64)).unwrap();
+        assert_eq!(power_consumption(&report), 0);
+        assert_eq!(life_support_rating(&report), u128::from(u64::MAX).pow(2));
+    }
+
+    #[test]
+    fn parse_diagnostic_report_invalid() {
+        let error = parse_diagnostic_report("0101\n011\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"2:1: expected a binary number of 4 bits, found "011""#
+        );
+        let error = parse_diagnostic_report("0101\n0121\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"2:3: expected a binary digit, found "2""#
+        );
+    }
+}
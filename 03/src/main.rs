@@ -0,0 +1,24 @@
+// Day 3: Binary Diagnostic
+// https://adventofcode.com/2021/day/3
+
+#![warn(clippy::pedantic)]
+
+use std::process;
+
+use aoc_2021_03::{life_support_rating, power_consumption, read_diagnostic_report};
+use aoc_common::InputSource;
+
+fn main() {
+    let report = read_diagnostic_report(InputSource::from_args()).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    println!(
+        "Part 1: Power consumption (gamma rate x epsilon rate) = {}",
+        power_consumption(&report)
+    );
+    println!(
+        "Part 2: Life support rating (oxygen generator rating x CO2 scrubber rating) = {}",
+        life_support_rating(&report)
+    );
+}
@@ -0,0 +1,191 @@
+// Day 4: Giant Squid
+// https://adventofcode.com/2021/day/4
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_common::{
+    comma_separated, optional_answer, parse_value, read_and_parse, sections, Error, InputSource,
+    ParseError, Solution,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = BingoGame;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<BingoGame>()
+    }
+
+    fn summarise_input(bingo_game: &Self::Input) -> String {
+        format!(
+            "{} numbers to be drawn, {} cards",
+            bingo_game.numbers_to_be_drawn.len(),
+            bingo_game.cards.len()
+        )
+    }
+
+    fn part_one(bingo_game: &Self::Input) -> Self::PartOne {
+        let (winning_score, _) = winning_losing_game_scores(bingo_game.clone());
+        optional_answer(winning_score)
+    }
+
+    fn part_two(bingo_game: &Self::Input) -> Self::PartTwo {
+        let (_, losing_score) = winning_losing_game_scores(bingo_game.clone());
+        optional_answer(losing_score)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or is not a valid bingo game.
+pub fn read_bingo_game(input: impl Into<InputSource>) -> Result<BingoGame, Error> {
+    read_and_parse(input, str::parse::<BingoGame>)
+}
+
+#[must_use]
+pub fn winning_losing_game_scores(mut bingo_game: BingoGame) -> (Option<u64>, Option<u64>) {
+    let mut scores = Vec::new();
+
+    for number in bingo_game.numbers_to_be_drawn {
+        for card in &mut bingo_game.cards {
+            if card.still_playing && card.mark_number(number) {
+                scores.push(number * card.sum_of_unmatched());
+            }
+        }
+    }
+
+    let winning_score = scores.first();
+    let losing_score = scores.last();
+    (winning_score.copied(), losing_score.copied())
+}
+
+#[derive(Clone, Debug)]
+pub struct BingoGame {
+    pub numbers_to_be_drawn: Vec<u64>,
+    pub cards: Vec<BingoCard>,
+}
+
+impl FromStr for BingoGame {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sections = sections(s);
+
+        // There are no sections at all if the input is empty.
+        let numbers_to_be_drawn: Vec<u64> = comma_separated(sections.next().unwrap_or(s))
+            .map(|number| parse_value(s, number, "a bingo number"))
+            .collect::<Result<_, _>>()?;
+
+        let cards: Vec<BingoCard> = sections
+            .map(|section| {
+                section
+                    .parse::<BingoCard>()
+                    .map_err(|error| error.within(s, section))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(BingoGame {
+            numbers_to_be_drawn,
+            cards,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BingoCard {
+    unmatched_number_locations: HashMap<u64, (usize, usize)>,
+    row_match_counts: HashMap<usize, usize>,
+    column_match_counts: HashMap<usize, usize>,
+    pub still_playing: bool,
+}
+
+impl BingoCard {
+    pub fn mark_number(&mut self, number: u64) -> bool {
+        if let Some((row_number, column_number)) = self.unmatched_number_locations.remove(&number) {
+            let row_match_count = self.row_match_counts.entry(row_number).or_insert(0);
+            let column_match_count = self.column_match_counts.entry(column_number).or_insert(0);
+            *row_match_count += 1;
+            *column_match_count += 1;
+            if *row_match_count == 5 || *column_match_count == 5 {
+                self.still_playing = false;
+                return true;
+            }
+        }
+        false
+    }
+
+    #[must_use]
+    pub fn sum_of_unmatched(&self) -> u64 {
+        self.unmatched_number_locations.keys().sum()
+    }
+}
+
+impl FromStr for BingoCard {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Rows are newline delimited, then columns by spaces. Eg:
+        // "22 13 17 11  0\n 8  2 23  4 24\n21  9 14 16  7\n 6 10  3 18  5\n 1 12 20 15 19"
+        // This is converted to a map of bingo number to (row, column) coordinates.
+        let number_locations: HashMap<u64, (usize, usize)> =
+            s.lines()
+                .enumerate()
+                .flat_map(|(row_num, row_string)| {
+                    row_string.split_whitespace().enumerate().map(
+                        move |(column_num, number_string)| {
+                            let number = parse_value(s, number_string, "a bingo number")?;
+                            Ok((number, (row_num, column_num)))
+                        },
+                    )
+                })
+                .collect::<Result<_, Self::Err>>()?;
+        Ok(Self {
+            unmatched_number_locations: number_locations,
+            row_match_counts: HashMap::new(),
+            column_match_counts: HashMap::new(),
+            still_playing: true,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let bingo_game = read_bingo_game(example_file()).unwrap();
+        let (winning_score, _) = winning_losing_game_scores(bingo_game);
+        assert_eq!(winning_score, Some(4512));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let bingo_game = read_bingo_game(example_file()).unwrap();
+        let (_, losing_score) = winning_losing_game_scores(bingo_game);
+        assert_eq!(losing_score, Some(1924));
+    }
+
+    #[test]
+    fn parse_bingo_game_invalid() {
+        let error = "7,4,9\n\n22 13\n 8  x".parse::<BingoGame>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"4:5: expected a bingo number, found "x""#
+        );
+    }
+}
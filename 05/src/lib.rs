@@ -0,0 +1,191 @@
+// Day 5: Hydrothermal Venture
+// https://adventofcode.com/2021/day/5
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::cmp::{self, Ordering};
+use std::str::FromStr;
+
+use aoc_common::{
+    parse_lines, parse_value, read_and_parse, Error, InputSource, ParseError, Solution,
+};
+use counter::Counter;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Line>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_vent_lines(input)
+    }
+
+    fn summarise_input(vent_lines: &Self::Input) -> String {
+        format!("{} vent lines", vent_lines.len())
+    }
+
+    fn part_one(vent_lines: &Self::Input) -> Self::PartOne {
+        total_points_with_intersections(vent_lines, false)
+    }
+
+    fn part_two(vent_lines: &Self::Input) -> Self::PartTwo {
+        total_points_with_intersections(vent_lines, true)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid line.
+pub fn read_vent_lines(input: impl Into<InputSource>) -> Result<Vec<Line>, Error> {
+    read_and_parse(input, parse_vent_lines)
+}
+
+/// # Errors
+///
+/// Returns an error if any of the lines are invalid.
+pub fn parse_vent_lines(input: &str) -> Result<Vec<Line>, ParseError> {
+    parse_lines(input)
+}
+
+#[must_use]
+pub fn total_points_with_intersections(vent_lines: &[Line], include_diagonals: bool) -> usize {
+    let all_line_points = vent_lines
+        .iter()
+        .filter(|line| include_diagonals || !line.is_diagonal())
+        .flat_map(Line::points_on_line);
+    let occurrences_of_each_point = all_line_points.collect::<Counter<_>>();
+    // If a point was seen more than once, more than one line must pass through it, meaning a line intersection there.
+    let points_with_intersections = occurrences_of_each_point
+        .values()
+        .filter(|&count| *count > 1);
+    points_with_intersections.count()
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Line {
+    #[must_use]
+    pub fn is_diagonal(&self) -> bool {
+        self.start.x != self.end.x && self.start.y != self.end.y
+    }
+
+    pub fn points_on_line(&self) -> impl Iterator<Item = Point> + '_ {
+        // Generate the list of points that exist on the line. For example, for a line with
+        // start (0,0) and end (3,3), this returns a Vec with elements:
+        // [(0,0), (1,1), (2,2), (3,3)]
+        // Only supports lines that are vertical, horizontal or at a 45 degree angle.
+        let total_points = 1 + cmp::max(
+            (self.end.x - self.start.x).abs(),
+            (self.end.y - self.start.y).abs(),
+        );
+        let x_step = match self.end.x.cmp(&self.start.x) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        };
+        let y_step = match self.end.y.cmp(&self.start.y) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        };
+        (0..total_points).map(move |num| Point {
+            x: self.start.x + num * x_step,
+            y: self.start.y + num * y_step,
+        })
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Example input: "0,9 -> 5,9"
+        let (start, end) = s
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::at(s, s, "two points separated by \" -> \""))?;
+        let line = Self {
+            start: start
+                .parse::<Point>()
+                .map_err(|error| error.within(s, start))?,
+            end: end.parse::<Point>().map_err(|error| error.within(s, end))?,
+        };
+        // Other angles aren't supported by `points_on_line()`.
+        let x_distance = (line.end.x - line.start.x).abs();
+        let y_distance = (line.end.y - line.start.y).abs();
+        if x_distance != 0 && y_distance != 0 && x_distance != y_distance {
+            return Err(ParseError::at(
+                s,
+                s,
+                "a horizontal, vertical or 45 degree diagonal line",
+            ));
+        }
+        Ok(line)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Example input: "0,9"
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(s, s, "a point in the form \"x,y\""))?;
+        Ok(Self {
+            x: parse_value(s, x, "an x coordinate")?,
+            y: parse_value(s, y, "a y coordinate")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let vent_lines = read_vent_lines(example_file()).unwrap();
+        assert_eq!(total_points_with_intersections(&vent_lines, false), 5);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let vent_lines = read_vent_lines(example_file()).unwrap();
+        assert_eq!(total_points_with_intersections(&vent_lines, true), 12);
+    }
+
+    #[test]
+    fn parse_vent_lines_invalid() {
+        let error = parse_vent_lines("0,9 -> 5,9\n8,0 -> 0,y").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"2:10: expected a y coordinate, found "y""#
+        );
+        let error = parse_vent_lines("0,9 -> 5,7").unwrap_err();
+        assert_eq!(error.column, 1);
+        assert_eq!(
+            error.expected,
+            "a horizontal, vertical or 45 degree diagonal line"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+// Day 6: Lanternfish
+// https://adventofcode.com/2021/day/6
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::implicit_hasher)]
+
+use std::collections::HashMap;
+
+use aoc_common::{comma_separated, read_and_parse, Error, InputSource, ParseError, Solution};
+use counter::Counter;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = HashMap<u8, usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_timer_value_counts(input)
+    }
+
+    fn summarise_input(timer_value_counts: &Self::Input) -> String {
+        format!("{} fish", timer_value_counts.values().sum::<usize>())
+    }
+
+    fn part_one(timer_value_counts: &Self::Input) -> Self::PartOne {
+        total_fish_after_days(timer_value_counts, 80)
+    }
+
+    fn part_two(timer_value_counts: &Self::Input) -> Self::PartTwo {
+        total_fish_after_days(timer_value_counts, 256)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid timer value.
+pub fn read_timer_value_counts(input: impl Into<InputSource>) -> Result<HashMap<u8, usize>, Error> {
+    read_and_parse(input, parse_timer_value_counts)
+}
+
+/// Parses a single line of comma delimited fish timer values (eg "3,4,3,1,2"),
+/// and returns a map of fish timer values to the count of fish with that value.
+///
+/// # Errors
+///
+/// Returns an error if any of the timer values are invalid.
+pub fn parse_timer_value_counts(input: &str) -> Result<HashMap<u8, usize>, ParseError> {
+    let timer_values = comma_separated(input)
+        .map(|timer_value| {
+            timer_value
+                .parse::<u8>()
+                .ok()
+                .filter(|value| *value <= 8)
+                .ok_or_else(|| ParseError::at(input, timer_value, "a timer value from 0 to 8"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(timer_values.into_iter().collect::<Counter<_>>().into_map())
+}
+
+/// # Panics
+///
+/// Panics if any timer value is greater than 8.
+#[must_use]
+pub fn total_fish_after_days(initial_timer_value_counts: &HashMap<u8, usize>, days: u16) -> usize {
+    let mut timer_value_counts = initial_timer_value_counts.clone();
+
+    for _ in 1..=days {
+        let mut new_timer_value_counts: HashMap<u8, usize> = HashMap::new();
+        for (&timer_value, &count) in &timer_value_counts {
+            match timer_value {
+                0 => {
+                    // Reset the zero-timer fish back to 6.
+                    let new_count = new_timer_value_counts.entry(6).or_insert(0);
+                    *new_count += count;
+                    // Add an identical number of new fish.
+                    // Not using the entry API since there will never be an existing entry for 8.
+                    new_timer_value_counts.insert(8, count);
+                }
+                1..=8 => {
+                    // Decrement the timer for all other fish.
+                    let new_count = new_timer_value_counts.entry(timer_value - 1).or_insert(0);
+                    *new_count += count;
+                }
+                _ => panic!("Invalid timer value: {timer_value}"),
+            }
+        }
+        timer_value_counts = new_timer_value_counts;
+    }
+
+    // Total fish.
+    timer_value_counts.values().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let timer_value_counts = read_timer_value_counts(example_file()).unwrap();
+        assert_eq!(total_fish_after_days(&timer_value_counts, 80), 5934);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let timer_value_counts = read_timer_value_counts(example_file()).unwrap();
+        assert_eq!(
+            total_fish_after_days(&timer_value_counts, 256),
+            26_984_457_539
+        );
+    }
+}
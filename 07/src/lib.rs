@@ -0,0 +1,115 @@
+// Day 7: The Treachery of Whales
+// https://adventofcode.com/2021/day/7
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use aoc_common::{parse_comma_separated, read_and_parse, Error, InputSource, ParseError, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<i64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_positions(input)
+    }
+
+    fn summarise_input(positions: &Self::Input) -> String {
+        format!("{} crab positions", positions.len())
+    }
+
+    fn part_one(positions: &Self::Input) -> Self::PartOne {
+        let (_, fuel_cost) = optimal_position_part_one(positions);
+        fuel_cost
+    }
+
+    fn part_two(positions: &Self::Input) -> Self::PartTwo {
+        let (_, fuel_cost) = optimal_position_part_two(positions);
+        fuel_cost
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid position.
+pub fn read_positions(input: impl Into<InputSource>) -> Result<Vec<i64>, Error> {
+    read_and_parse(input, parse_positions)
+}
+
+/// # Errors
+///
+/// Returns an error if any of the positions are invalid.
+pub fn parse_positions(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse_comma_separated(input, "a position")
+}
+
+#[must_use]
+pub fn optimal_position_part_one(positions: &[i64]) -> (i64, u64) {
+    // We can skip comparing the fuel cost from every possible position since the
+    // median will always be the optimal position. If there are an even number of
+    // elements both will have the same fuel cost, so it's fine to pick either.
+    let mut positions = positions.to_vec();
+    positions.sort_unstable();
+    let midpoint = positions.len() / 2;
+    let median_position = positions[midpoint];
+    let total_fuel = positions.iter().fold(0, |total_fuel, position| {
+        let moves_required = (position - median_position).unsigned_abs();
+        total_fuel + moves_required
+    });
+    (median_position, total_fuel)
+}
+
+#[must_use]
+pub fn optimal_position_part_two(positions: &[i64]) -> (i64, u64) {
+    // Since fuel cost is now equivalent to the triangular number of moves performed
+    // by each crab, the optimal position is no longer equivalent to the median.
+    let min_position = *positions.iter().min().unwrap_or(&0);
+    let max_position = *positions.iter().max().unwrap_or(&0);
+    let (optimal_position, total_fuel) = (min_position..=max_position)
+        .map(|proposed_position| {
+            let total_fuel = positions.iter().fold(0, |total_fuel, position| {
+                let moves_required = (position - proposed_position).unsigned_abs();
+                total_fuel + triangular_number(moves_required)
+            });
+            (proposed_position, total_fuel)
+        })
+        .min_by_key(|(_, total_fuel)| *total_fuel)
+        .unwrap_or_default();
+    (optimal_position, total_fuel)
+}
+
+// https://en.wikipedia.org/wiki/Triangular_number
+fn triangular_number(n: u64) -> u64 {
+    n * (n + 1) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let positions = read_positions(example_file()).unwrap();
+        let (position, fuel_cost) = optimal_position_part_one(&positions);
+        assert_eq!(position, 2);
+        assert_eq!(fuel_cost, 37);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let positions = read_positions(example_file()).unwrap();
+        let (position, fuel_cost) = optimal_position_part_two(&positions);
+        assert_eq!(position, 5);
+        assert_eq!(fuel_cost, 168);
+    }
+}
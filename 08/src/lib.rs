@@ -0,0 +1,237 @@
+// Day 8: Seven Segment Search
+// https://adventofcode.com/2021/day/8
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_common::{parse_lines, read_and_parse, Error, InputSource, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Entry>;
+    type PartOne = usize;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_entries(input)
+    }
+
+    fn summarise_input(entries: &Self::Input) -> String {
+        format!("{} entries", entries.len())
+    }
+
+    fn part_one(entries: &Self::Input) -> Self::PartOne {
+        total_easy_digits(entries)
+    }
+
+    fn part_two(entries: &Self::Input) -> Self::PartTwo {
+        sum_of_output_values(entries)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid entry.
+pub fn read_entries(input: impl Into<InputSource>) -> Result<Vec<Entry>, Error> {
+    read_and_parse(input, parse_entries)
+}
+
+/// # Errors
+///
+/// Returns an error if any of the entries are invalid.
+pub fn parse_entries(input: &str) -> Result<Vec<Entry>, ParseError> {
+    parse_lines(input)
+}
+
+#[must_use]
+pub fn total_easy_digits(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .flat_map(|entry| {
+            entry
+                .output
+                .iter()
+                .filter(|pattern| matches!(pattern.len(), 2..=4 | 7))
+        })
+        .count()
+}
+
+/// # Panics
+///
+/// Panics if an entry's output contains a pattern that cannot be decoded.
+#[must_use]
+pub fn sum_of_output_values(entries: &[Entry]) -> u64 {
+    entries.iter().map(decode_entry).sum()
+}
+
+/// # Panics
+///
+/// Panics if the entry's output contains a pattern that cannot be decoded.
+#[must_use]
+pub fn decode_entry(entry: &Entry) -> u64 {
+    let mut patterns_to_numbers: HashMap<String, u8> = HashMap::new();
+    let mut numbers_to_patterns: HashMap<u8, String> = HashMap::new();
+
+    while patterns_to_numbers.len() < 10 {
+        for pattern in &entry.patterns {
+            if patterns_to_numbers.contains_key(pattern) {
+                continue;
+            }
+            if let Some(number) = try_decode_pattern(pattern, &numbers_to_patterns) {
+                patterns_to_numbers.insert(pattern.clone(), number);
+                numbers_to_patterns.insert(number, pattern.clone());
+            }
+        }
+    }
+
+    entry
+        .output
+        .iter()
+        .map(|pattern| {
+            patterns_to_numbers
+                .get(pattern)
+                .expect("Unknown pattern found in output!")
+        })
+        .join("")
+        .parse()
+        .unwrap_or_default()
+}
+
+fn try_decode_pattern(pattern: &str, numbers_to_patterns: &HashMap<u8, String>) -> Option<u8> {
+    match pattern.len() {
+        // Some segment counts are unique, so can be mapped immediately to a digit.
+        2 => Some(1),
+        3 => Some(7),
+        4 => Some(4),
+        7 => Some(8),
+        length => {
+            // For the others, we need have to wait until we have the patterns for 1 and 4,
+            // in order that we can deduce the pattern's value.
+            let pattern_for_one = numbers_to_patterns.get(&1)?;
+            let pattern_for_four = numbers_to_patterns.get(&4)?;
+            match length {
+                5 => {
+                    if pattern_contains_another(pattern, pattern_for_one) {
+                        Some(3)
+                    } else if characters_overlap_between_patterns(pattern, pattern_for_four) == 2 {
+                        Some(2)
+                    } else {
+                        Some(5)
+                    }
+                }
+                6 => {
+                    if pattern_contains_another(pattern, pattern_for_four) {
+                        Some(9)
+                    } else if pattern_contains_another(pattern, pattern_for_one) {
+                        Some(0)
+                    } else {
+                        Some(6)
+                    }
+                }
+                _ => panic!("Invalid pattern length!"),
+            }
+        }
+    }
+}
+
+fn pattern_contains_another(pattern: &str, sub_pattern: &str) -> bool {
+    sub_pattern.chars().all(|c| pattern.contains(c))
+}
+
+fn characters_overlap_between_patterns(pattern1: &str, pattern2: &str) -> usize {
+    pattern1.chars().filter(|&c| pattern2.contains(c)).count()
+}
+
+pub struct Entry {
+    pub patterns: Vec<String>,
+    pub output: Vec<String>,
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // eg: "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe"
+        let (patterns, output) = s
+            .split_once('|')
+            .ok_or_else(|| ParseError::at(s, s, "patterns and output separated by \"|\""))?;
+        Ok(Self {
+            patterns: parse_patterns(s, patterns, 10)?,
+            output: parse_patterns(s, output, 4)?,
+        })
+    }
+}
+
+fn parse_patterns(
+    entry: &str,
+    section: &str,
+    expected_count: usize,
+) -> Result<Vec<String>, ParseError> {
+    let patterns = section
+        .split_whitespace()
+        .map(|pattern| {
+            if pattern.chars().all(|c| matches!(c, 'a'..='g')) {
+                // Sort the letters in each word alphabetically, to aid matching later.
+                Ok(pattern.chars().sorted().collect())
+            } else {
+                Err(ParseError::at(entry, pattern, "segments a to g"))
+            }
+        })
+        .collect::<Result<Vec<String>, _>>()?;
+    if patterns.len() == expected_count {
+        Ok(patterns)
+    } else {
+        Err(ParseError::at(
+            entry,
+            section,
+            format!("{expected_count} patterns"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let entries = read_entries(example_file()).unwrap();
+        assert_eq!(total_easy_digits(&entries), 26);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let entries = read_entries(example_file()).unwrap();
+        assert_eq!(sum_of_output_values(&entries), 61229);
+    }
+
+    #[test]
+    fn decode_entry_testcase_one() {
+        let entry = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab |
+        cdfeb fcadb cdfeb cdbaf"
+            .parse::<Entry>()
+            .unwrap();
+        assert_eq!(decode_entry(&entry), 5353);
+    }
+
+    #[test]
+    fn decode_entry_testcase_two() {
+        let entry = "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd |
+        ed bcgafe cdgba cbgef"
+            .parse::<Entry>()
+            .unwrap();
+        assert_eq!(decode_entry(&entry), 1625);
+    }
+}
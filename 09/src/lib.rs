@@ -0,0 +1,164 @@
+// Day 9: Smoke Basin
+// https://adventofcode.com/2021/day/9
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::collections::HashMap;
+
+use aoc_common::{parse_digit_grid, read_and_parse, Error, InputSource, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<u32>>;
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_heights(input)
+    }
+
+    fn summarise_input(heights: &Self::Input) -> String {
+        format!(
+            "{}x{} heightmap",
+            heights.first().map_or(0, Vec::len),
+            heights.len()
+        )
+    }
+
+    fn part_one(heights: &Self::Input) -> Self::PartOne {
+        low_points_total_risk(heights)
+    }
+
+    fn part_two(heights: &Self::Input) -> Self::PartTwo {
+        largest_three_basin_sizes_multiplied(heights)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid height.
+pub fn read_heights(input: impl Into<InputSource>) -> Result<Vec<Vec<u32>>, Error> {
+    read_and_parse(input, parse_heights)
+}
+
+/// # Errors
+///
+/// Returns an error if any of the heights are not a single digit.
+pub fn parse_heights(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parse_digit_grid(input, "a height from 0 to 9")
+}
+
+#[must_use]
+pub fn low_points_total_risk(heights: &[Vec<u32>]) -> u32 {
+    const MAX_HEIGHT: u32 = 9;
+    let mut total_risk = 0;
+    let mut row_above: Option<&Vec<u32>> = None;
+
+    for (row_num, row) in heights.iter().enumerate() {
+        let mut previous_height: Option<&u32> = None;
+        for (col_num, height) in row.iter().enumerate() {
+            let height_left = previous_height.unwrap_or(&MAX_HEIGHT);
+            let height_right = row.get(col_num + 1).unwrap_or(&MAX_HEIGHT);
+            let height_above = row_above
+                .and_then(|row| row.get(col_num))
+                .unwrap_or(&MAX_HEIGHT);
+            let height_below = heights
+                .get(row_num + 1)
+                .and_then(|row| row.get(col_num))
+                .unwrap_or(&MAX_HEIGHT);
+            if height < height_left
+                && height < height_right
+                && height < height_above
+                && height < height_below
+            {
+                total_risk += height + 1;
+            }
+            previous_height = Some(height);
+        }
+        row_above = Some(row);
+    }
+    total_risk
+}
+
+#[must_use]
+pub fn largest_three_basin_sizes_multiplied(heights: &[Vec<u32>]) -> usize {
+    let mut latest_basin_id: usize = 0;
+    let mut points_to_basin_id: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut basin_sizes: HashMap<usize, usize> = HashMap::new();
+
+    for (row_num, row) in heights.iter().enumerate() {
+        for (col_num, height) in row.iter().enumerate() {
+            // Heights of 9 aren't counted in the basin.
+            if *height == 9 {
+                continue;
+            }
+
+            let basin_id_left = if col_num > 0 {
+                points_to_basin_id.get(&(row_num, col_num - 1))
+            } else {
+                None
+            };
+            let basin_id_above = if row_num > 0 {
+                points_to_basin_id.get(&(row_num - 1, col_num))
+            } else {
+                None
+            };
+
+            let current_basin_id = match (basin_id_left, basin_id_above) {
+                // This current location joins up two previously separate basins, so we need to
+                // merge the two basin IDs. Here we chose to merge into the ID of the basin above.
+                (Some(&id_left), Some(&id_above)) => {
+                    // Update all points mapped to the left basin to instead map to the basin above.
+                    points_to_basin_id
+                        .values_mut()
+                        .filter(|id| **id == id_left)
+                        .for_each(|id| *id = id_above);
+                    // And update the basin size mappings accordingly.
+                    let left_basin_size = basin_sizes.remove(&id_left).unwrap_or_default();
+                    let above_basin_size = basin_sizes.entry(id_above).or_insert(0);
+                    *above_basin_size += left_basin_size;
+                    id_above
+                }
+                // There is a single existing basin, so continue using its ID.
+                (Some(id), None) | (None, Some(id)) => *id,
+                // This is a new basin, so generate a new ID.
+                (None, None) => {
+                    latest_basin_id += 1;
+                    latest_basin_id
+                }
+            };
+            points_to_basin_id.insert((row_num, col_num), current_basin_id);
+            let size = basin_sizes.entry(current_basin_id).or_insert(0);
+            *size += 1;
+        }
+    }
+
+    basin_sizes.values().sorted().rev().take(3).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let heights = read_heights(example_file()).unwrap();
+        assert_eq!(low_points_total_risk(&heights), 15);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let heights = read_heights(example_file()).unwrap();
+        assert_eq!(largest_three_basin_sizes_multiplied(&heights), 1134);
+    }
+}
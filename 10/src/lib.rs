@@ -0,0 +1,190 @@
+// Day 10: Syntax Scoring
+// https://adventofcode.com/2021/day/10
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use aoc_common::{read_and_parse, Error, InputSource, ParseError, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Vec<char>>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn summarise_input(lines: &Self::Input) -> String {
+        format!("{} lines", lines.len())
+    }
+
+    fn part_one(lines: &Self::Input) -> Self::PartOne {
+        corrupted_lines_total_score(lines)
+    }
+
+    fn part_two(lines: &Self::Input) -> Self::PartTwo {
+        incomplete_lines_median_score(lines)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an unknown symbol.
+pub fn read_lines(input: impl Into<InputSource>) -> Result<Vec<Vec<char>>, Error> {
+    read_and_parse(input, parse_lines)
+}
+
+/// # Errors
+///
+/// Returns an error if any of the lines contain a symbol other than a bracket.
+pub fn parse_lines(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(index, symbol)| match symbol {
+                    '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>' => Ok(symbol),
+                    _ => {
+                        let text = &line[index..index + symbol.len_utf8()];
+                        Err(ParseError::at(input, text, "a bracket"))
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// # Panics
+///
+/// Panics if a line contains an unknown symbol.
+#[must_use]
+pub fn corrupted_lines_total_score(lines: &[Vec<char>]) -> u64 {
+    lines
+        .iter()
+        .filter_map(|line| {
+            if let LineType::Corrupted(score) = analyse_line(line) {
+                Some(score)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+/// # Panics
+///
+/// Panics if a line contains an unknown symbol, or there are no incomplete lines.
+#[must_use]
+pub fn incomplete_lines_median_score(lines: &[Vec<char>]) -> u64 {
+    let mut scores: Vec<u64> = lines
+        .iter()
+        .filter_map(|line| {
+            if let LineType::Incomplete(score) = analyse_line(line) {
+                Some(score)
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    scores.sort_unstable();
+    let midpoint = scores.len() / 2;
+    scores[midpoint]
+}
+
+/// # Panics
+///
+/// Panics if the line contains an unknown symbol.
+#[must_use]
+pub fn analyse_line(line: &[char]) -> LineType {
+    let mut open_chunks: Vec<char> = Vec::new();
+
+    for &symbol in line {
+        match symbol {
+            '(' | '[' | '{' | '<' => open_chunks.push(symbol),
+            ')' | ']' | '}' | '>' => {
+                let expected_closing_symbol = open_chunks.pop().map(expected_closing_symbol);
+                if expected_closing_symbol != Some(symbol) {
+                    return LineType::Corrupted(corrupted_symbol_score(symbol));
+                }
+            }
+            _ => panic!("Unknown symbol: {symbol}"),
+        }
+    }
+
+    if open_chunks.is_empty() {
+        LineType::Valid
+    } else {
+        LineType::Incomplete(incomplete_chunks_score(&open_chunks))
+    }
+}
+
+fn expected_closing_symbol(opening_symbol: char) -> char {
+    match opening_symbol {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '<' => '>',
+        _ => panic!("Unknown symbol: {opening_symbol}"),
+    }
+}
+
+fn corrupted_symbol_score(symbol: char) -> u64 {
+    match symbol {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => panic!("Unknown symbol: {symbol}"),
+    }
+}
+
+fn incomplete_chunks_score(open_chunks: &[char]) -> u64 {
+    open_chunks.iter().rev().fold(0, |total, &opening_symbol| {
+        let missing_symbol = expected_closing_symbol(opening_symbol);
+        total * 5 + missing_symbol_score(missing_symbol)
+    })
+}
+
+fn missing_symbol_score(symbol: char) -> u64 {
+    match symbol {
+        ')' => 1,
+        ']' => 2,
+        '}' => 3,
+        '>' => 4,
+        _ => panic!("Unknown symbol: {symbol}"),
+    }
+}
+
+pub enum LineType {
+    Valid,
+    Corrupted(u64),
+    Incomplete(u64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let lines = read_lines(example_file()).unwrap();
+        assert_eq!(corrupted_lines_total_score(&lines), 26397);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let lines = read_lines(example_file()).unwrap();
+        assert_eq!(incomplete_lines_median_score(&lines), 288_957);
+    }
+}
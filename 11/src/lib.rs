@@ -0,0 +1,151 @@
+// Day 11: Dumbo Octopus
+// https://adventofcode.com/2021/day/11
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::implicit_hasher)]
+
+use std::collections::HashMap;
+
+use aoc_common::{
+    grid_points, parse_digit_grid, read_and_parse, Error, InputSource, ParseError, Solution,
+};
+
+// Returns a map of octopus locations to energy levels.
+// Side note: It turns out "octopi" is not grammatically correct:
+// https://en.wikipedia.org/wiki/Octopus#Etymology_and_pluralisation
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = HashMap<Point, u32>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_octopuses(input)
+    }
+
+    fn summarise_input(octopuses: &Self::Input) -> String {
+        format!("{} octopuses", octopuses.len())
+    }
+
+    fn part_one(octopuses: &Self::Input) -> Self::PartOne {
+        total_flashes(octopuses.clone(), 100)
+    }
+
+    fn part_two(octopuses: &Self::Input) -> Self::PartTwo {
+        first_step_when_synchronised(octopuses.clone())
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid energy level.
+pub fn read_octopuses(input: impl Into<InputSource>) -> Result<HashMap<Point, u32>, Error> {
+    read_and_parse(input, parse_octopuses)
+}
+
+/// # Errors
+///
+/// Returns an error if any of the energy levels are not a single digit.
+pub fn parse_octopuses(input: &str) -> Result<HashMap<Point, u32>, ParseError> {
+    let energy_levels = parse_digit_grid(input, "an energy level from 0 to 9")?;
+    Ok(grid_points(energy_levels))
+}
+
+#[must_use]
+pub fn total_flashes(mut octopuses: HashMap<Point, u32>, steps: u64) -> u64 {
+    let mut total_flashes = 0;
+    for _ in 1..=steps {
+        total_flashes += perform_step(&mut octopuses);
+    }
+    total_flashes
+}
+
+#[must_use]
+pub fn first_step_when_synchronised(mut octopuses: HashMap<Point, u32>) -> u64 {
+    let total_octopuses = octopuses.len() as u64;
+    let mut step = 0;
+
+    loop {
+        step += 1;
+        let flashes = perform_step(&mut octopuses);
+        if flashes == total_octopuses {
+            return step;
+        }
+    }
+}
+
+fn perform_step(octopuses: &mut HashMap<Point, u32>) -> u64 {
+    let mut flashes = 0;
+    let mut pending_flash: Vec<Point> = Vec::new();
+
+    for (point, energy_level) in octopuses.iter_mut() {
+        *energy_level += 1;
+        if *energy_level == 10 {
+            pending_flash.push(*point);
+        }
+    }
+
+    while let Some(point) = pending_flash.pop() {
+        flashes += 1;
+
+        for adjacent_point in get_adjacent_points(point) {
+            if let Some(energy_level) = octopuses.get_mut(&adjacent_point) {
+                *energy_level += 1;
+                if *energy_level == 10 {
+                    pending_flash.push(adjacent_point);
+                }
+            }
+        }
+    }
+
+    // Reset all flashed octopuses back to zero.
+    octopuses
+        .values_mut()
+        .filter(|energy_level| **energy_level > 9)
+        .for_each(|energy_level| *energy_level = 0);
+
+    flashes
+}
+
+fn get_adjacent_points((row, column): Point) -> impl Iterator<Item = Point> {
+    [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ]
+    .iter()
+    .map(move |(row_offset, column_offset)| (row + row_offset, column + column_offset))
+}
+
+pub type Point = (i64, i64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let octopuses = read_octopuses(example_file()).unwrap();
+        assert_eq!(total_flashes(octopuses, 100), 1656);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let octopuses = read_octopuses(example_file()).unwrap();
+        assert_eq!(first_step_when_synchronised(octopuses), 195);
+    }
+}
@@ -0,0 +1,163 @@
+// Day 12: Passage Pathing
+// https://adventofcode.com/2021/day/12
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::implicit_hasher)]
+
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{read_and_parse, Error, InputSource, ParseError, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = HashMap<String, Vec<String>>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_graph(input)
+    }
+
+    fn summarise_input(graph: &Self::Input) -> String {
+        format!("{} caves", graph.len())
+    }
+
+    fn part_one(graph: &Self::Input) -> Self::PartOne {
+        total_paths(graph, true)
+    }
+
+    fn part_two(graph: &Self::Input) -> Self::PartTwo {
+        total_paths(graph, false)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid edge.
+pub fn read_graph(input: impl Into<InputSource>) -> Result<HashMap<String, Vec<String>>, Error> {
+    read_and_parse(input, parse_graph)
+}
+
+/// # Errors
+///
+/// Returns an error if any of the edges are invalid.
+pub fn parse_graph(input: &str) -> Result<HashMap<String, Vec<String>>, ParseError> {
+    let edges = input
+        .lines()
+        .map(|line| {
+            let (start_node, end_node) = line
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(input, line, "two caves separated by \"-\""))?;
+            Ok((start_node.to_string(), end_node.to_string()))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+    for (start_node, end_node) in edges {
+        let start_node_neighbours = graph.entry(start_node.clone()).or_default();
+        start_node_neighbours.push(end_node.clone());
+        let end_node_neighbours = graph.entry(end_node).or_default();
+        end_node_neighbours.push(start_node);
+    }
+    Ok(graph)
+}
+
+#[must_use]
+pub fn total_paths(graph: &HashMap<String, Vec<String>>, only_visit_small_caves_once: bool) -> u64 {
+    let mut unexplored_routes = vec![Route {
+        current_node: "start",
+        visited: HashSet::new(),
+        small_cave_visited_twice: false,
+    }];
+    let mut completed_routes = 0;
+
+    while let Some(mut route) = unexplored_routes.pop() {
+        if route.current_node == "end" {
+            completed_routes += 1;
+            continue;
+        }
+
+        route.visited.insert(route.current_node);
+
+        if let Some(adjacent_nodes) = graph.get(route.current_node) {
+            for adjacent_node in adjacent_nodes {
+                let mut small_cave_visited_twice = route.small_cave_visited_twice;
+                if is_small_cave(adjacent_node) && route.visited.contains(adjacent_node.as_str()) {
+                    if adjacent_node == "start"
+                        || only_visit_small_caves_once
+                        || small_cave_visited_twice
+                    {
+                        continue;
+                    }
+                    small_cave_visited_twice = true;
+                }
+                unexplored_routes.push(Route {
+                    current_node: adjacent_node,
+                    visited: route.visited.clone(),
+                    small_cave_visited_twice,
+                });
+            }
+        }
+    }
+
+    completed_routes
+}
+
+fn is_small_cave(name: &str) -> bool {
+    name == name.to_lowercase()
+}
+
+struct Route<'a> {
+    current_node: &'a str,
+    visited: HashSet<&'a str>,
+    small_cave_visited_twice: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file(example_number: u8) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("example{example_number}.txt"))
+    }
+
+    #[test]
+    fn part_one_example1() {
+        let graph = read_graph(example_file(1)).unwrap();
+        assert_eq!(total_paths(&graph, true), 10);
+    }
+
+    #[test]
+    fn part_one_example2() {
+        let graph = read_graph(example_file(2)).unwrap();
+        assert_eq!(total_paths(&graph, true), 19);
+    }
+
+    #[test]
+    fn part_one_example3() {
+        let graph = read_graph(example_file(3)).unwrap();
+        assert_eq!(total_paths(&graph, true), 226);
+    }
+
+    #[test]
+    fn part_two_example1() {
+        let graph = read_graph(example_file(1)).unwrap();
+        assert_eq!(total_paths(&graph, false), 36);
+    }
+
+    #[test]
+    fn part_two_example2() {
+        let graph = read_graph(example_file(2)).unwrap();
+        assert_eq!(total_paths(&graph, false), 103);
+    }
+
+    #[test]
+    fn part_two_example3() {
+        let graph = read_graph(example_file(3)).unwrap();
+        assert_eq!(total_paths(&graph, false), 3509);
+    }
+}
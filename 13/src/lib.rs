@@ -0,0 +1,186 @@
+// Day 13: Transparent Origami
+// https://adventofcode.com/2021/day/13
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::implicit_hasher)]
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_common::{
+    parse_value, read_and_parse, render_grid, sections, Error, InputSource, ParseError, Solution,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Paper;
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<Paper>()
+    }
+
+    fn summarise_input(paper: &Self::Input) -> String {
+        format!("{} dots, {} folds", paper.dots.len(), paper.folds.len())
+    }
+
+    fn part_one(paper: &Self::Input) -> Self::PartOne {
+        total_dots_after_first_fold(paper)
+    }
+
+    fn part_two(paper: &Self::Input) -> Self::PartTwo {
+        decoded_message(paper)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or is not valid transparent paper.
+pub fn read_paper(input: impl Into<InputSource>) -> Result<Paper, Error> {
+    read_and_parse(input, str::parse::<Paper>)
+}
+
+#[must_use]
+pub fn total_dots_after_first_fold(paper: &Paper) -> usize {
+    if let Some(fold) = paper.folds.first() {
+        get_dots_after_fold(&paper.dots, fold).len()
+    } else {
+        0
+    }
+}
+
+#[must_use]
+pub fn decoded_message(paper: &Paper) -> String {
+    let final_dots = paper.folds.iter().fold(paper.dots.clone(), |dots, fold| {
+        get_dots_after_fold(&dots, fold)
+    });
+
+    dots_to_message(&final_dots)
+}
+
+#[must_use]
+pub fn get_dots_after_fold(dots: &HashSet<(u64, u64)>, folds: &Fold) -> HashSet<(u64, u64)> {
+    dots.iter()
+        .filter_map(|(x, y)| {
+            // If dots are above/to the left of the fold line, then they are preserved.
+            // If they are on the fold line, they are dropped.
+            // Or if the are below/to the right of the fold line, then they must be translated.
+            match folds {
+                Fold::X(fold_at) => match x.cmp(fold_at) {
+                    Ordering::Less => Some((*x, *y)),
+                    Ordering::Equal => None,
+                    Ordering::Greater => Some((2 * fold_at - x, *y)),
+                },
+                Fold::Y(fold_at) => match y.cmp(fold_at) {
+                    Ordering::Less => Some((*x, *y)),
+                    Ordering::Equal => None,
+                    Ordering::Greater => Some((*x, 2 * fold_at - y)),
+                },
+            }
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn dots_to_message(dots: &HashSet<(u64, u64)>) -> String {
+    let max_x = dots.iter().map(|(x, _)| *x).max().unwrap_or(0);
+    let max_y = dots.iter().map(|(_, y)| *y).max().unwrap_or(0);
+
+    render_grid(0..=max_y, 0..=max_x, |y, x| dots.contains(&(*x, *y)), ' ')
+}
+
+pub struct Paper {
+    pub dots: HashSet<(u64, u64)>,
+    pub folds: Vec<Fold>,
+}
+
+impl FromStr for Paper {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sections = sections(s);
+
+        let dots = sections
+            .next()
+            .unwrap_or_default()
+            .lines()
+            .map(|point| {
+                // eg: "6,10"
+                let (x, y) = point
+                    .split_once(',')
+                    .ok_or_else(|| ParseError::at(s, point, "a dot in the form \"x,y\""))?;
+                Ok((
+                    parse_value(s, x, "an x coordinate")?,
+                    parse_value(s, y, "a y coordinate")?,
+                ))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        let folds = sections
+            .next()
+            .unwrap_or_default()
+            .lines()
+            .map(|fold| fold.parse::<Fold>().map_err(|error| error.within(s, fold)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { dots, folds })
+    }
+}
+
+pub enum Fold {
+    X(u64),
+    Y(u64),
+}
+
+impl FromStr for Fold {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // eg: "fold along y=7"
+        s.split_once('=')
+            .and_then(|(axis, value)| {
+                let value = value.parse::<u64>().ok()?;
+                match axis {
+                    "fold along x" => Some(Fold::X(value)),
+                    "fold along y" => Some(Fold::Y(value)),
+                    _ => None,
+                }
+            })
+            .ok_or_else(|| ParseError::at(s, s, "a fold instruction such as \"fold along y=7\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let paper = read_paper(example_file()).unwrap();
+        assert_eq!(total_dots_after_first_fold(&paper), 17);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let paper = read_paper(example_file()).unwrap();
+        let expected_message = indoc! {"
+            #####
+            #   #
+            #   #
+            #   #
+            #####"};
+        assert_eq!(decoded_message(&paper), expected_message);
+    }
+}
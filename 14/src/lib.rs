@@ -0,0 +1,163 @@
+// Day 14: Extended Polymerization
+// https://adventofcode.com/2021/day/14
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_common::{read_and_parse, sections, Error, InputSource, ParseError, Solution};
+use counter::Counter;
+use itertools::Itertools;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Instructions;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<Instructions>()
+    }
+
+    fn summarise_input(instructions: &Self::Input) -> String {
+        format!("{} pair insertion rules", instructions.rules.len())
+    }
+
+    fn part_one(instructions: &Self::Input) -> Self::PartOne {
+        calculate_result(instructions, 10)
+    }
+
+    fn part_two(instructions: &Self::Input) -> Self::PartTwo {
+        calculate_result(instructions, 40)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains invalid instructions.
+pub fn read_instructions(input: impl Into<InputSource>) -> Result<Instructions, Error> {
+    read_and_parse(input, str::parse::<Instructions>)
+}
+
+#[must_use]
+pub fn calculate_result(instructions: &Instructions, steps: u8) -> usize {
+    let mut template_pairs = instructions.template_pairs.clone();
+
+    for _ in 1..=steps {
+        template_pairs = perform_step(&template_pairs, &instructions.rules);
+    }
+
+    let element_counts =
+        template_pairs
+            .iter()
+            .fold(HashMap::new(), |mut counts, ((element_a, _), count)| {
+                *counts.entry(element_a).or_insert(0) += count;
+                counts
+            });
+
+    let most_common_quantity = element_counts.values().max().unwrap_or(&0);
+    let least_common_quantity = element_counts.values().min().unwrap_or(&0);
+
+    most_common_quantity - least_common_quantity
+}
+
+#[must_use]
+pub fn perform_step(template_pairs: &TemplatePairs, rules: &Rules) -> TemplatePairs {
+    let mut new_template_pairs = HashMap::new();
+
+    for ((element_a, element_b), count) in template_pairs {
+        if let Some(element_to_insert) = rules.get(&(*element_a, *element_b)) {
+            *new_template_pairs
+                .entry((*element_a, Some(*element_to_insert)))
+                .or_insert(0) += *count;
+            *new_template_pairs
+                .entry((Some(*element_to_insert), *element_b))
+                .or_insert(0) += *count;
+        } else {
+            *new_template_pairs
+                .entry((*element_a, *element_b))
+                .or_insert(0) += *count;
+        }
+    }
+
+    new_template_pairs
+}
+
+pub type TemplatePairs = HashMap<(Option<char>, Option<char>), usize>;
+pub type Rules = HashMap<(Option<char>, Option<char>), char>;
+
+pub struct Instructions {
+    pub template_pairs: TemplatePairs,
+    pub rules: Rules,
+}
+
+impl FromStr for Instructions {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sections = sections(s);
+
+        let template = sections
+            .next()
+            .ok_or_else(|| ParseError::at(s, s, "a polymer template"))?;
+        let mut template_pairs = template
+            .chars()
+            .tuple_windows::<(_, _)>()
+            .map(|(element_a, element_b)| (Some(element_a), Some(element_b)))
+            .collect::<Counter<_>>()
+            .into_map();
+        // We need to add the last character on to ensure the counts are correct later,
+        // since tuple_windows() stops when there are no more complete pairs.
+        let last_character = template.chars().last();
+        template_pairs.insert((last_character, None), 1);
+
+        let rules = sections
+            .next()
+            .ok_or_else(|| ParseError::at(s, &s[s.len()..], "pair insertion rules"))?
+            .lines()
+            .map(|rule| {
+                rule.split_once(" -> ")
+                    .and_then(|(element_pair, b)| {
+                        let (element_a, element_b) = element_pair.chars().collect_tuple()?;
+                        let element_to_insert = b.chars().next()?;
+                        Some(((Some(element_a), Some(element_b)), element_to_insert))
+                    })
+                    .ok_or_else(|| {
+                        ParseError::at(s, rule, "a pair insertion rule such as \"CH -> B\"")
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            template_pairs,
+            rules,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let instructions = read_instructions(example_file()).unwrap();
+        assert_eq!(calculate_result(&instructions, 10), 1588);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let instructions = read_instructions(example_file()).unwrap();
+        assert_eq!(calculate_result(&instructions, 40), 2_188_189_693_529);
+    }
+}
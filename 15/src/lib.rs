@@ -0,0 +1,163 @@
+// Day 15: Chiton
+// https://adventofcode.com/2021/day/15
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::implicit_hasher)]
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use aoc_common::{
+    grid_points, parse_grid, read_and_parse, Error, InputSource, ParseError, Solution,
+};
+use priority_queue::PriorityQueue;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = HashMap<Point, u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_risk_levels(input)
+    }
+
+    fn summarise_input(risk_levels: &Self::Input) -> String {
+        format!("{} positions", risk_levels.len())
+    }
+
+    fn part_one(risk_levels: &Self::Input) -> Self::PartOne {
+        lowest_total_risk(risk_levels)
+    }
+
+    fn part_two(risk_levels: &Self::Input) -> Self::PartTwo {
+        let expanded_risk_levels = generate_expanded_map(risk_levels, 5);
+        lowest_total_risk(&expanded_risk_levels)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid risk level.
+pub fn read_risk_levels(input: impl Into<InputSource>) -> Result<HashMap<Point, u32>, Error> {
+    read_and_parse(input, parse_risk_levels)
+}
+
+/// # Errors
+///
+/// Returns an error if any of the risk levels are not a single digit.
+pub fn parse_risk_levels(input: &str) -> Result<HashMap<Point, u32>, ParseError> {
+    let risk_levels = parse_grid(input, "a risk level from 1 to 9", |char| {
+        char.to_digit(10).filter(|digit| *digit > 0)
+    })?;
+    Ok(grid_points(risk_levels))
+}
+
+#[must_use]
+pub fn lowest_total_risk(risk_levels: &HashMap<Point, u32>) -> u32 {
+    let mut risk_from_source: HashMap<Point, u32> = HashMap::new();
+    // Using unpopulated (aside from start point) priority queue, since was quicker than the
+    // populated variant (440ms vs 610ms for `cargo run --release input.txt`). Timings can be
+    // compared using `cargo run --release -p aoc -- bench 15`. See more about Dijkstra's
+    // variants here:
+    // https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm#Using_a_priority_queue
+    let mut queue = PriorityQueue::new();
+
+    let start_point = (0, 0);
+    let end_point = *risk_levels.keys().max().unwrap_or(&start_point);
+    risk_from_source.insert(start_point, 0);
+    queue.push(start_point, Reverse(0));
+
+    while let Some((point, Reverse(total_risk))) = queue.pop() {
+        if point == end_point {
+            break;
+        }
+
+        for adjacent_point in get_adjacent_points(point) {
+            if let Some(adjacent_risk) = risk_levels.get(&adjacent_point) {
+                let new_adjacent_total_risk = total_risk + adjacent_risk;
+                let existing_adjacent_total_risk =
+                    risk_from_source.get(&adjacent_point).unwrap_or(&u32::MAX);
+
+                if new_adjacent_total_risk < *existing_adjacent_total_risk {
+                    risk_from_source.insert(adjacent_point, new_adjacent_total_risk);
+                    queue.push_increase(adjacent_point, Reverse(new_adjacent_total_risk));
+                }
+            }
+        }
+    }
+
+    *risk_from_source.get(&end_point).unwrap_or(&0)
+}
+
+fn get_adjacent_points((row, column): Point) -> impl Iterator<Item = Point> {
+    [(-1, 0), (0, -1), (0, 1), (1, 0)]
+        .iter()
+        .map(move |(row_offset, column_offset)| (row + row_offset, column + column_offset))
+}
+
+/// # Panics
+///
+/// Panics if the map of risk levels is empty.
+#[must_use]
+pub fn generate_expanded_map(
+    risk_levels: &HashMap<Point, u32>,
+    multiplier: u8,
+) -> HashMap<Point, u32> {
+    let grid_width = *risk_levels.keys().map(|(x, _)| x).max().unwrap() + 1;
+
+    risk_levels
+        .iter()
+        .flat_map(|((x, y), risk)| {
+            (0..multiplier).flat_map(move |x_offset| {
+                (0..multiplier).map(move |y_offset| {
+                    let new_x = x + i64::from(x_offset) * grid_width;
+                    let new_y = y + i64::from(y_offset) * grid_width;
+                    let new_risk = (risk + u32::from(x_offset + y_offset) - 1) % 9 + 1;
+                    ((new_x, new_y), new_risk)
+                })
+            })
+        })
+        .collect()
+}
+
+pub type Point = (i64, i64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn part_one_example() {
+        let risk_levels = read_risk_levels(example_file()).unwrap();
+        assert_eq!(lowest_total_risk(&risk_levels), 40);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let risk_levels = read_risk_levels(example_file()).unwrap();
+        let expanded_risk_levels = generate_expanded_map(&risk_levels, 5);
+        assert_eq!(lowest_total_risk(&expanded_risk_levels), 315);
+    }
+
+    #[test]
+    fn part_two_generate_expanded_map() {
+        let expanded_example_file =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("example-expanded.txt");
+        let expected_expanded_risk_levels = read_risk_levels(expanded_example_file).unwrap();
+        let original_risk_levels = read_risk_levels(example_file()).unwrap();
+        assert_eq!(
+            generate_expanded_map(&original_risk_levels, 5),
+            expected_expanded_risk_levels
+        );
+    }
+}
@@ -0,0 +1,552 @@
+// Day 16: Packet Decoder
+// https://adventofcode.com/2021/day/16
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::str::FromStr;
+
+use aoc_common::{optional_answer, read_and_parse, Error, InputSource, ParseError, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Packet;
+    type PartOne = u64;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<Packet>()
+    }
+
+    fn summarise_input(packet: &Self::Input) -> String {
+        format!("{} packets", packet.total_packets())
+    }
+
+    fn part_one(packet: &Self::Input) -> Self::PartOne {
+        packet.version_sum()
+    }
+
+    fn part_two(packet: &Self::Input) -> Self::PartTwo {
+        optional_answer(packet.value())
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or is not a valid BITS transmission.
+pub fn read_packet(input: impl Into<InputSource>) -> Result<Packet, Error> {
+    read_and_parse(input, str::parse::<Packet>)
+}
+
+const LITERAL_TYPE_ID: u64 = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Packet {
+    pub version: u8,
+    pub contents: Contents,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Contents {
+    Literal(u64),
+    Operator {
+        operation: Operation,
+        // Retained so that the packet is encoded in the same way that it was received.
+        length_type: LengthType,
+        sub_packets: Vec<Packet>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+impl Operation {
+    fn from_type_id(type_id: u64) -> Option<Self> {
+        match type_id {
+            0 => Some(Operation::Sum),
+            1 => Some(Operation::Product),
+            2 => Some(Operation::Minimum),
+            3 => Some(Operation::Maximum),
+            5 => Some(Operation::GreaterThan),
+            6 => Some(Operation::LessThan),
+            7 => Some(Operation::EqualTo),
+            _ => None,
+        }
+    }
+
+    fn type_id(self) -> u64 {
+        match self {
+            Operation::Sum => 0,
+            Operation::Product => 1,
+            Operation::Minimum => 2,
+            Operation::Maximum => 3,
+            Operation::GreaterThan => 5,
+            Operation::LessThan => 6,
+            Operation::EqualTo => 7,
+        }
+    }
+
+    fn is_comparison(self) -> bool {
+        matches!(
+            self,
+            Operation::GreaterThan | Operation::LessThan | Operation::EqualTo
+        )
+    }
+}
+
+// How the extent of an operator packet's sub-packets is specified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthType {
+    TotalBits,
+    PacketCount,
+}
+
+impl Packet {
+    #[must_use]
+    pub fn version_sum(&self) -> u64 {
+        let sub_packets_sum: u64 = self.sub_packets().iter().map(Packet::version_sum).sum();
+        u64::from(self.version) + sub_packets_sum
+    }
+
+    /// Evaluates the expression represented by this packet and its sub-packets, or returns
+    /// `None` if a sum or product overflows a `u64`.
+    #[must_use]
+    pub fn value(&self) -> Option<u64> {
+        match &self.contents {
+            Contents::Literal(value) => Some(*value),
+            Contents::Operator {
+                operation,
+                sub_packets,
+                ..
+            } => {
+                let values: Vec<u64> = sub_packets
+                    .iter()
+                    .map(Packet::value)
+                    .collect::<Option<_>>()?;
+                let mut values = values.into_iter();
+                match operation {
+                    Operation::Sum => values.try_fold(0, u64::checked_add),
+                    Operation::Product => values.try_fold(1, u64::checked_mul),
+                    Operation::Minimum => Some(values.min().unwrap_or_default()),
+                    Operation::Maximum => Some(values.max().unwrap_or_default()),
+                    // Comparison packets are guaranteed to have exactly two sub-packets.
+                    Operation::GreaterThan => Some(u64::from(values.next() > values.next())),
+                    Operation::LessThan => Some(u64::from(values.next() < values.next())),
+                    Operation::EqualTo => Some(u64::from(values.next() == values.next())),
+                }
+            }
+        }
+    }
+
+    /// The number of packets in the tree, including this one.
+    #[must_use]
+    pub fn total_packets(&self) -> usize {
+        1 + self
+            .sub_packets()
+            .iter()
+            .map(Packet::total_packets)
+            .sum::<usize>()
+    }
+
+    /// Encodes the packet as a hexadecimal BITS transmission, the inverse of `parse()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any packet's version doesn't fit in 3 bits, or if the sub-packets of an
+    /// operator packet are too long (or too many) to be represented using its length type.
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        let mut bits = BitWriter::default();
+        bits.write_packet(self);
+        bits.into_hex()
+    }
+
+    fn sub_packets(&self) -> &[Packet] {
+        match &self.contents {
+            Contents::Literal(_) => &[],
+            Contents::Operator { sub_packets, .. } => sub_packets,
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bits = BitReader::new(s, s.trim())?;
+        // Any bits remaining after the outermost packet are padding, so are ignored.
+        bits.read_packet()
+    }
+}
+
+struct BitReader<'a> {
+    input: &'a str,
+    hex: &'a str,
+    bits: Vec<bool>,
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(input: &'a str, hex: &'a str) -> Result<Self, ParseError> {
+        let mut bits = Vec::with_capacity(hex.len() * 4);
+        for (index, char) in hex.char_indices() {
+            let digit = char.to_digit(16).ok_or_else(|| {
+                let text = &hex[index..index + char.len_utf8()];
+                ParseError::at(input, text, "a hexadecimal digit")
+            })?;
+            bits.extend((0..4).rev().map(|bit| digit & (1 << bit) != 0));
+        }
+        Ok(Self {
+            input,
+            hex,
+            bits,
+            position: 0,
+        })
+    }
+
+    fn read(&mut self, count: usize) -> Result<u64, ParseError> {
+        let end = self.position + count;
+        let bits = self
+            .bits
+            .get(self.position..end)
+            .ok_or_else(|| self.error_at(self.bits.len(), "the rest of the packet"))?;
+        self.position = end;
+        Ok(bits
+            .iter()
+            .fold(0, |value, bit| (value << 1) | u64::from(*bit)))
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn read_packet(&mut self) -> Result<Packet, ParseError> {
+        let start = self.position;
+        let version = self.read(3)? as u8;
+        let type_id = self.read(3)?;
+
+        let contents = match Operation::from_type_id(type_id) {
+            None => Contents::Literal(self.read_literal()?),
+            Some(operation) => {
+                let (length_type, sub_packets) = self.read_sub_packets()?;
+                let valid_count = if operation.is_comparison() {
+                    sub_packets.len() == 2
+                } else {
+                    !sub_packets.is_empty()
+                };
+                if !valid_count {
+                    let expected = if operation.is_comparison() {
+                        "a comparison packet with exactly two sub-packets"
+                    } else {
+                        "an operator packet with at least one sub-packet"
+                    };
+                    return Err(self.error_at(start, expected));
+                }
+                Contents::Operator {
+                    operation,
+                    length_type,
+                    sub_packets,
+                }
+            }
+        };
+        Ok(Packet { version, contents })
+    }
+
+    fn read_literal(&mut self) -> Result<u64, ParseError> {
+        // The value is split into groups of 4 bits, each prefixed by a bit that is set
+        // for every group except the last.
+        let start = self.position;
+        let mut value: u64 = 0;
+        loop {
+            let group = self.read(5)?;
+            if value.leading_zeros() < 4 {
+                return Err(self.error_at(start, "a literal value that fits in 64 bits"));
+            }
+            value = (value << 4) | (group & 0b1111);
+            if group & 0b1_0000 == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn read_sub_packets(&mut self) -> Result<(LengthType, Vec<Packet>), ParseError> {
+        let mut sub_packets = Vec::new();
+        if self.read(1)? == 0 {
+            let length = self.read(15)? as usize;
+            let start = self.position;
+            while self.position < start + length {
+                sub_packets.push(self.read_packet()?);
+            }
+            if self.position != start + length {
+                let expected = format!("sub-packets totalling {length} bits");
+                return Err(self.error_at(start, &expected));
+            }
+            Ok((LengthType::TotalBits, sub_packets))
+        } else {
+            let count = self.read(11)?;
+            for _ in 0..count {
+                sub_packets.push(self.read_packet()?);
+            }
+            Ok((LengthType::PacketCount, sub_packets))
+        }
+    }
+
+    // Errors are located at the hex digit that contains the given bit.
+    fn error_at(&self, bit: usize, expected: &str) -> ParseError {
+        let digit = bit / 4;
+        let text = self
+            .hex
+            .get(digit..=digit)
+            .unwrap_or(&self.hex[self.hex.len()..]);
+        ParseError::at(self.input, text, expected)
+    }
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bits: Vec<bool>,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, count: usize) {
+        self.bits
+            .extend((0..count).rev().map(|bit| value & (1 << bit) != 0));
+    }
+
+    fn write_packet(&mut self, packet: &Packet) {
+        assert!(
+            packet.version < 1 << 3,
+            "Packet version too large to encode"
+        );
+        self.write(u64::from(packet.version), 3);
+        match &packet.contents {
+            Contents::Literal(value) => {
+                self.write(LITERAL_TYPE_ID, 3);
+                self.write_literal(*value);
+            }
+            Contents::Operator {
+                operation,
+                length_type,
+                sub_packets,
+            } => {
+                self.write(operation.type_id(), 3);
+                self.write_sub_packets(*length_type, sub_packets);
+            }
+        }
+    }
+
+    fn write_literal(&mut self, value: u64) {
+        // Uses the fewest groups possible, though there is always at least one.
+        let significant_bits = 64 - value.leading_zeros() as usize;
+        let groups = significant_bits.div_ceil(4).max(1);
+        for group in (0..groups).rev() {
+            let continuation = u64::from(group > 0);
+            self.write((continuation << 4) | ((value >> (group * 4)) & 0b1111), 5);
+        }
+    }
+
+    fn write_sub_packets(&mut self, length_type: LengthType, sub_packets: &[Packet]) {
+        match length_type {
+            LengthType::TotalBits => {
+                let mut sub_packet_bits = BitWriter::default();
+                for sub_packet in sub_packets {
+                    sub_packet_bits.write_packet(sub_packet);
+                }
+                let length = sub_packet_bits.bits.len();
+                assert!(length < 1 << 15, "Sub-packets too long to encode");
+                self.write(0, 1);
+                self.write(length as u64, 15);
+                self.bits.extend(sub_packet_bits.bits);
+            }
+            LengthType::PacketCount => {
+                assert!(
+                    sub_packets.len() < 1 << 11,
+                    "Too many sub-packets to encode"
+                );
+                self.write(1, 1);
+                self.write(sub_packets.len() as u64, 11);
+                for sub_packet in sub_packets {
+                    self.write_packet(sub_packet);
+                }
+            }
+        }
+    }
+
+    // The transmission is padded with zeros to a whole number of bytes.
+    fn into_hex(mut self) -> String {
+        let padded_length = self.bits.len().div_ceil(8) * 8;
+        self.bits.resize(padded_length, false);
+        self.bits
+            .chunks(4)
+            .map(|nibble| {
+                let digit = nibble
+                    .iter()
+                    .fold(0, |digit, bit| (digit << 1) | u32::from(*bit));
+                char::from_digit(digit, 16)
+                    .expect("Nibble is a valid hex digit")
+                    .to_ascii_uppercase()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    fn literal(version: u8, value: u64) -> Packet {
+        Packet {
+            version,
+            contents: Contents::Literal(value),
+        }
+    }
+
+    #[test]
+    fn decode_literal_packet() {
+        assert_eq!("D2FE28".parse::<Packet>().unwrap(), literal(6, 2021));
+    }
+
+    #[test]
+    fn decode_operator_packets() {
+        assert_eq!(
+            "38006F45291200".parse::<Packet>().unwrap(),
+            Packet {
+                version: 1,
+                contents: Contents::Operator {
+                    operation: Operation::LessThan,
+                    length_type: LengthType::TotalBits,
+                    sub_packets: vec![literal(6, 10), literal(2, 20)],
+                },
+            }
+        );
+        assert_eq!(
+            "EE00D40C823060".parse::<Packet>().unwrap(),
+            Packet {
+                version: 7,
+                contents: Contents::Operator {
+                    operation: Operation::Maximum,
+                    length_type: LengthType::PacketCount,
+                    sub_packets: vec![literal(2, 1), literal(4, 2), literal(1, 3)],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn part_one_example() {
+        let packet = read_packet(example_file()).unwrap();
+        assert_eq!(packet.version_sum(), 31);
+        for (hex, version_sum) in [
+            ("8A004A801A8002F478", 16),
+            ("620080001611562C8802118E34", 12),
+            ("C0015000016115A2E0802F182340", 23),
+        ] {
+            assert_eq!(hex.parse::<Packet>().unwrap().version_sum(), version_sum);
+        }
+    }
+
+    #[test]
+    fn part_two_example() {
+        for (hex, value) in [
+            ("C200B40A82", 3),
+            ("04005AC33890", 54),
+            ("880086C3E88112", 7),
+            ("CE00C43D881120", 9),
+            ("D8005AC2A8F0", 1),
+            ("F600BC2D8F", 0),
+            ("9C005AC2F8F0", 0),
+            ("9C0141080250320F1802104A08", 1),
+        ] {
+            assert_eq!(hex.parse::<Packet>().unwrap().value(), Some(value));
+        }
+    }
+
+    #[test]
+    fn value_overflow() {
+        let operator = |operation, values: &[u64]| Packet {
+            version: 0,
+            contents: Contents::Operator {
+                operation,
+                length_type: LengthType::PacketCount,
+                sub_packets: values.iter().map(|&value| literal(0, value)).collect(),
+            },
+        };
+        assert_eq!(operator(Operation::Sum, &[u64::MAX, 1]).value(), None);
+        assert_eq!(operator(Operation::Product, &[u64::MAX, 2]).value(), None);
+        assert_eq!(
+            operator(Operation::Sum, &[u64::MAX - 1, 1]).value(),
+            Some(u64::MAX)
+        );
+        // Overflow within a sub-packet makes the whole expression overflow.
+        let nested = Packet {
+            version: 0,
+            contents: Contents::Operator {
+                operation: Operation::Minimum,
+                length_type: LengthType::PacketCount,
+                sub_packets: vec![operator(Operation::Sum, &[u64::MAX, 1]), literal(0, 1)],
+            },
+        };
+        assert_eq!(nested.value(), None);
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        for hex in [
+            "D2FE28",
+            "38006F45291200",
+            "EE00D40C823060",
+            "8A004A801A8002F478",
+            "620080001611562C8802118E34",
+            "C0015000016115A2E0802F182340",
+            "A0016C880162017C3686B18A3D4780",
+            "9C0141080250320F1802104A08",
+        ] {
+            let packet = hex.parse::<Packet>().unwrap();
+            assert_eq!(packet.to_hex(), hex);
+            assert_eq!(packet.to_hex().parse::<Packet>().unwrap(), packet);
+        }
+        let packet = Packet {
+            version: 3,
+            contents: Contents::Operator {
+                operation: Operation::Product,
+                length_type: LengthType::TotalBits,
+                sub_packets: vec![literal(0, 0), literal(7, u64::MAX)],
+            },
+        };
+        assert_eq!(packet.to_hex().parse::<Packet>().unwrap(), packet);
+    }
+
+    #[test]
+    #[should_panic(expected = "Packet version too large to encode")]
+    fn encode_invalid_version() {
+        let _ = literal(8, 1).to_hex();
+    }
+
+    #[test]
+    fn parse_packet_invalid() {
+        let error = "D2FG28".parse::<Packet>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"1:4: expected a hexadecimal digit, found "G""#
+        );
+        let error = "D2FE".parse::<Packet>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "1:5: expected the rest of the packet, found nothing"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+// Day 16: Packet Decoder
+// https://adventofcode.com/2021/day/16
+
+#![warn(clippy::pedantic)]
+
+use std::process;
+
+use aoc_2021_16::read_packet;
+use aoc_common::{optional_answer, InputSource};
+
+fn main() {
+    let packet = read_packet(InputSource::from_args()).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    println!(
+        "Part 1: Sum of all version numbers = {}",
+        packet.version_sum()
+    );
+    println!(
+        "Part 2: Value of the outermost packet = {}",
+        optional_answer(packet.value())
+    );
+}
@@ -0,0 +1,1209 @@
+// Day 17: Trick Shot
+// https://adventofcode.com/2021/day/17
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+#![allow(clippy::similar_names)]
+
+use std::cmp;
+use std::collections::HashSet;
+use std::iter;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use aoc_common::{
+    optional_answer, parse_value, read_and_parse, render_cells, Error, InputSource, ParseError,
+    Solution,
+};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Target;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<Target>()
+    }
+
+    fn summarise_input(target: &Self::Input) -> String {
+        format!(
+            "target area x={}..{}, y={}..{}",
+            target.x.start(),
+            target.x.end(),
+            target.y.start(),
+            target.y.end()
+        )
+    }
+
+    fn part_one(target: &Self::Input) -> Self::PartOne {
+        optional_answer(max_height_that_reaches_target(target, &Physics::default()))
+    }
+
+    fn part_two(target: &Self::Input) -> Self::PartTwo {
+        optional_answer(total_velocities_that_reach_target(
+            target,
+            &Physics::default(),
+        ))
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or is not a valid target area.
+pub fn read_target(input: impl Into<InputSource>) -> Result<Target, Error> {
+    read_and_parse(input, str::parse::<Target>)
+}
+
+/// The rules governing how the probe's velocity changes after each step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Physics {
+    // How much the x velocity changes towards zero (without changing direction).
+    pub drag: i64,
+    // How much the y velocity decreases.
+    pub gravity: i64,
+    // How much the x velocity increases, after drag has been applied.
+    pub wind: i64,
+    // The most steps a shot is simulated for before it's considered to have missed.
+    pub max_steps: Option<usize>,
+}
+
+impl Default for Physics {
+    // The rules from the puzzle.
+    fn default() -> Self {
+        Physics {
+            drag: 1,
+            gravity: 1,
+            wind: 0,
+            max_steps: None,
+        }
+    }
+}
+
+impl Physics {
+    fn next_velocity(self, (x_velocity, y_velocity): (i64, i64)) -> (i64, i64) {
+        let x_velocity = if x_velocity.abs() <= self.drag {
+            0
+        } else {
+            x_velocity - x_velocity.signum() * self.drag
+        };
+        (x_velocity + self.wind, y_velocity - self.gravity)
+    }
+
+    // Whether the step ranges can be calculated with the closed-form approach, which relies
+    // on the velocity changing by exactly 1 each step.
+    fn has_closed_form(self) -> bool {
+        (self.drag, self.gravity, self.wind) == (1, 1, 0)
+    }
+}
+
+/// The highest y position of any shot which reaches the target. Returns `None` if no shot
+/// reaches the target, or if shots can be fired arbitrarily high and still reach it (see
+/// `total_velocities_that_reach_target`).
+#[must_use]
+pub fn max_height_that_reaches_target(target: &Target, physics: &Physics) -> Option<i64> {
+    if physics.has_closed_form() {
+        velocities_that_reach_target(target, physics)?
+            .next()
+            .map(|(_, y_velocity)| max_height(y_velocity))
+    } else {
+        // The order of the velocities can't be relied upon with other physics (such as with
+        // negative gravity), so every hit is considered.
+        hits_on_target(target, physics)?
+            .map(|hit| hit.max_height)
+            .max()
+    }
+}
+
+/// The number of distinct velocities which reach the target. Returns `None` if there are
+/// infinitely many, such as when the target spans y=0 and the probe can come to rest within its
+/// x range, since every shot fired upwards from then on falls back through the target. With
+/// wind, targets spanning y=0 also return `None` unless `max_steps` is set, since the velocities
+/// can't be limited otherwise.
+#[must_use]
+pub fn total_velocities_that_reach_target(target: &Target, physics: &Physics) -> Option<usize> {
+    let limits = velocity_limits(target, physics)?;
+    Some(if physics.has_closed_form() {
+        total_velocities_closed_form(target, physics.max_steps, limits)
+    } else {
+        velocities_that_reach_target_simulated(target, physics, limits).count()
+    })
+}
+
+// Returns `None` if there are infinitely many velocities which reach the target.
+fn velocities_that_reach_target<'a>(
+    target: &'a Target,
+    physics: &'a Physics,
+) -> Option<Box<dyn Iterator<Item = (i64, i64)> + 'a>> {
+    let limits = velocity_limits(target, physics)?;
+    Some(if physics.has_closed_form() {
+        Box::new(velocities_that_reach_target_closed_form(
+            target,
+            physics.max_steps,
+            limits,
+        ))
+    } else {
+        Box::new(velocities_that_reach_target_simulated(
+            target, physics, limits,
+        ))
+    })
+}
+
+// The fastest that the probe can be fired (in either direction) horizontally and vertically
+// and still reach the target, and the last step on which it can do so (if there is one).
+#[derive(Clone, Copy, Debug)]
+struct Limits {
+    x_speed: i64,
+    y_speed: i64,
+    last_step: Option<i64>,
+}
+
+// Returns `None` if there are no such limits (or they can't be determined).
+fn velocity_limits(target: &Target, physics: &Physics) -> Option<Limits> {
+    let furthest_x = cmp::max(target.x.start().abs(), target.x.end().abs());
+    let furthest_y = cmp::max(target.y.start().abs(), target.y.end().abs());
+    let last_step = last_step_in_target(target, physics);
+    // If the velocity changes by at most `change` each step, then after `step` steps the
+    // position is within `change * step * (step - 1) / 2` of `velocity * step`. For that to be
+    // within the target, the velocity can be at most `furthest + change * (step - 1) / 2`. If
+    // the probe can't change direction, it must instead reach the target on the first step.
+    let speed_limit = |furthest: i64, change: i64, can_change_direction: bool| {
+        if can_change_direction {
+            last_step.map(|step| furthest.saturating_add(change.saturating_mul(step) / 2))
+        } else {
+            Some(furthest)
+        }
+    };
+    Some(Limits {
+        // Drag slows the probe without changing its direction, unlike wind.
+        x_speed: speed_limit(
+            furthest_x,
+            physics.drag.abs() + physics.wind.abs(),
+            physics.wind != 0,
+        )?,
+        y_speed: speed_limit(furthest_y, physics.gravity.abs(), physics.gravity != 0)?,
+        last_step,
+    })
+}
+
+// The last step on which any shot can be within the target, or `None` if there isn't one.
+fn last_step_in_target(target: &Target, physics: &Physics) -> Option<i64> {
+    let furthest_x = cmp::max(target.x.start().abs(), target.x.end().abs());
+    let furthest_y = cmp::max(target.y.start().abs(), target.y.end().abs());
+    let last_step = if !target.y.contains(&0) {
+        // The y position after `step` steps is `velocity * step - gravity * (step - 1) * step / 2`,
+        // which is a multiple of `step` (or of `step / 2`, if `step` is even). Since the target
+        // doesn't include y=0, it can only be reached within twice as many steps as its
+        // furthest y coordinate.
+        Some(2 * furthest_y)
+    } else if physics.wind == 0 && !can_rest_within(&target.x, physics.drag) {
+        // Without wind, the probe moves at least one position further from x=0 each step
+        // until it comes to rest, so (since it can't rest within the target) can only be
+        // within it for as many steps as its furthest x coordinate.
+        Some(furthest_x)
+    } else {
+        None
+    };
+    let max_steps = physics
+        .max_steps
+        .map(|max_steps| i64::try_from(max_steps).unwrap_or(i64::MAX));
+    match (last_step, max_steps) {
+        (Some(last_step), Some(max_steps)) => Some(last_step.min(max_steps)),
+        (last_step, max_steps) => last_step.or(max_steps),
+    }
+}
+
+// Whether, without wind, the probe can be fired such that it comes to rest at an x position
+// within the target.
+fn can_rest_within(target_x: &RangeInclusive<i64>, drag: i64) -> bool {
+    let furthest_x = cmp::max(target_x.start().abs(), target_x.end().abs());
+    // The probe always comes to rest further from x=0 than the speed it was fired at.
+    (0..=furthest_x)
+        .filter_map(|speed| resting_position(speed, drag))
+        .any(|position| target_x.contains(&position) || target_x.contains(&-position))
+}
+
+// The x position at which a probe fired at the given speed (to the right, without wind) comes
+// to rest, or `None` if it never does.
+fn resting_position(speed: i64, drag: i64) -> Option<i64> {
+    if speed == 0 {
+        Some(0)
+    } else if drag > 0 {
+        // The probe slows down by `drag` each step, until it's moving at most that fast.
+        let moving_steps = (speed + drag - 1) / drag;
+        Some(speed * moving_steps - drag * moving_steps * (moving_steps - 1) / 2)
+    } else {
+        None
+    }
+}
+
+// Rather than simulating each shot, the steps at which the x and y positions are within the
+// target are calculated independently for each x and y velocity. A pair of velocities then
+// reaches the target if those steps overlap. Listing every pair takes time proportional to the
+// product of the width and height of the target, so `total_velocities_closed_form` should be
+// used when only the number of pairs is needed.
+fn velocities_that_reach_target_closed_form(
+    target: &Target,
+    max_steps: Option<usize>,
+    limits: Limits,
+) -> impl Iterator<Item = (i64, i64)> + '_ {
+    let x_steps: Rc<Vec<(i64, Steps)>> = Rc::new(
+        x_velocity_range(target)
+            .filter_map(|x_velocity| {
+                x_steps_within_max_steps(target, x_velocity, max_steps)
+                    .map(|steps| (x_velocity, steps))
+            })
+            .collect(),
+    );
+
+    y_velocity_range(target, limits)
+        // In descending order of Y velocity, so the highest height shots are first.
+        .rev()
+        .map(|y_velocity| (y_velocity, y_steps_in_target(&target.y, y_velocity)))
+        .filter(|(_, y_steps)| !y_steps.is_empty())
+        .flat_map(move |(y_velocity, y_steps)| {
+            let x_steps = Rc::clone(&x_steps);
+            (0..x_steps.len()).filter_map(move |index| {
+                let (x_velocity, steps) = x_steps[index];
+                y_steps
+                    .iter()
+                    .any(|y_steps| steps.intersection(*y_steps).is_some())
+                    .then_some((x_velocity, y_velocity))
+            })
+        })
+}
+
+// Counts the velocities which reach the target, in time proportional to the width plus the
+// height of the target (with a logarithmic factor), by counting the x velocities whose steps
+// overlap with the steps of each y velocity using binary search, rather than checking each one.
+fn total_velocities_closed_form(
+    target: &Target,
+    max_steps: Option<usize>,
+    limits: Limits,
+) -> usize {
+    // A faster probe reaches any given x position no later than a slower one fired in the same
+    // direction, so both the first and last steps within the target never increase with speed.
+    // The steps of the x velocities in each direction are therefore sorted in order of speed.
+    let x_steps = |x_velocity| x_steps_within_max_steps(target, x_velocity, max_steps);
+    let x_velocities = x_velocity_range(target);
+    let leftwards: Vec<Steps> = (*x_velocities.start()..0)
+        .rev()
+        .filter_map(&x_steps)
+        .collect();
+    let rightwards: Vec<Steps> = (0..=*x_velocities.end()).filter_map(&x_steps).collect();
+    let overlapping =
+        |steps: Steps| total_overlapping(&leftwards, steps) + total_overlapping(&rightwards, steps);
+
+    y_velocity_range(target, limits)
+        .map(|y_velocity| {
+            match y_steps_in_target(&target.y, y_velocity)[..] {
+                [steps] => overlapping(steps),
+                // The x steps are contiguous, so those which overlap with both the rising and
+                // falling steps are the ones which span the gap between them, and would
+                // otherwise be counted twice.
+                [rising, falling] => {
+                    overlapping(rising) + overlapping(falling)
+                        - overlapping(Steps {
+                            first: falling.first,
+                            last: rising.last,
+                        })
+                }
+                _ => 0,
+            }
+        })
+        .sum()
+}
+
+// The number of steps (which must be sorted as described in `total_velocities_closed_form`)
+// that overlap with `other`. The steps that start no later than `other` ends are a suffix, and
+// the steps that end no earlier than `other` starts are a prefix, so the overlap is between.
+fn total_overlapping(sorted_steps: &[Steps], other: Steps) -> usize {
+    let start =
+        sorted_steps.partition_point(|steps| other.last.is_some_and(|last| steps.first > last));
+    let end =
+        sorted_steps.partition_point(|steps| steps.last.is_none_or(|last| last >= other.first));
+    end.saturating_sub(start)
+}
+
+fn x_velocity_range(target: &Target) -> RangeInclusive<i64> {
+    cmp::min(*target.x.start(), 0)..=cmp::max(*target.x.end(), 0)
+}
+
+// A probe fired downwards any faster than the bottom of the target passes straight under it.
+fn y_velocity_range(target: &Target, limits: Limits) -> RangeInclusive<i64> {
+    cmp::min(*target.y.start(), 0)..=limits.y_speed
+}
+
+fn x_steps_within_max_steps(
+    target: &Target,
+    x_velocity: i64,
+    max_steps: Option<usize>,
+) -> Option<Steps> {
+    let allowed_steps = Steps {
+        first: 1,
+        last: max_steps.map(|max_steps| i64::try_from(max_steps).unwrap_or(i64::MAX)),
+    };
+    x_steps_in_target(&target.x, x_velocity)?.intersection(allowed_steps)
+}
+
+// With other physics the probe may be blown or pulled back towards the target, so velocities
+// in every direction (up to the limits) are simulated.
+fn velocities_that_reach_target_simulated<'a>(
+    target: &'a Target,
+    physics: &'a Physics,
+    limits: Limits,
+) -> impl Iterator<Item = (i64, i64)> + 'a {
+    // No shot can reach the target after the last step, so there's no need to simulate beyond it.
+    let physics = Physics {
+        max_steps: limits
+            .last_step
+            .map(|last_step| usize::try_from(last_step).unwrap_or(0)),
+        ..*physics
+    };
+    let Limits {
+        x_speed, y_speed, ..
+    } = limits;
+
+    (-y_speed..=y_speed)
+        .rev()
+        .flat_map(move |y_velocity| {
+            (-x_speed..=x_speed).map(move |x_velocity| (x_velocity, y_velocity))
+        })
+        .filter(move |&(x_velocity, y_velocity)| {
+            shoot(target, x_velocity, y_velocity, &physics).is_some()
+        })
+}
+
+// The highest y position is at the peak of the trajectory, unless fired downwards.
+fn max_height(y_velocity: i64) -> i64 {
+    if y_velocity > 0 {
+        y_velocity * (y_velocity + 1) / 2
+    } else {
+        0
+    }
+}
+
+// An inclusive range of the steps at which one coordinate of the probe is within the target,
+// with no last step if it comes to rest inside the target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Steps {
+    first: i64,
+    last: Option<i64>,
+}
+
+impl Steps {
+    fn new(first: Option<i64>, last: Option<i64>) -> Option<Steps> {
+        let steps = Steps {
+            first: first?,
+            last,
+        };
+        (!steps.is_empty()).then_some(steps)
+    }
+
+    fn is_empty(self) -> bool {
+        self.last.is_some_and(|last| last < self.first)
+    }
+
+    fn intersection(self, other: Steps) -> Option<Steps> {
+        let last = match (self.last, other.last) {
+            (Some(last), Some(other_last)) => Some(last.min(other_last)),
+            (last, other_last) => last.or(other_last),
+        };
+        Steps::new(Some(self.first.max(other.first)), last)
+    }
+}
+
+// The position after `step` steps, for a velocity that decreases by 1 each step. This is the
+// sum of an arithmetic series: `velocity + (velocity - 1) + ... + (velocity - step + 1)`.
+fn position_after(velocity: i64, step: i64) -> i64 {
+    velocity * step - step * (step - 1) / 2
+}
+
+fn x_steps_in_target(target_x: &RangeInclusive<i64>, velocity: i64) -> Option<Steps> {
+    // Drag acts towards zero, so a probe fired to the left mirrors one fired to the right.
+    if velocity < 0 {
+        return x_steps_in_target(&(-*target_x.end()..=-*target_x.start()), -velocity);
+    }
+    let (start, end) = (*target_x.start(), *target_x.end());
+    // Drag stops the probe moving horizontally once the velocity reaches zero.
+    let position = |step: i64| position_after(velocity, step.min(velocity));
+    let resting_position = position(velocity);
+    if resting_position < start {
+        return None;
+    }
+    let first = first_step_where(
+        step_estimate(velocity, start, Root::Lower),
+        1,
+        None,
+        |step| position(step) >= start,
+    );
+    let last = if resting_position > end {
+        first_step_where(step_estimate(velocity, end, Root::Lower), 1, None, |step| {
+            position(step) > end
+        })
+        .map(|overshot| overshot - 1)
+    } else {
+        None
+    };
+    Steps::new(first, last)
+}
+
+// Gravity means the probe rises (for `velocity` steps, if fired upwards) and then falls, so it
+// may pass through the target twice: once on the way up, and again on the way down.
+fn y_steps_in_target(target_y: &RangeInclusive<i64>, velocity: i64) -> Vec<Steps> {
+    let (start, end) = (*target_y.start(), *target_y.end());
+    let position = |step: i64| position_after(velocity, step);
+    let peak_step = velocity.max(0);
+
+    let rising_first = first_step_where(
+        step_estimate(velocity, start, Root::Lower),
+        1,
+        Some(peak_step),
+        |step| position(step) >= start,
+    );
+    let rising_last = first_step_where(
+        step_estimate(velocity, end, Root::Lower),
+        1,
+        Some(peak_step),
+        |step| position(step) > end,
+    )
+    .map_or(peak_step, |overshot| overshot - 1);
+
+    let falling_first = first_step_where(
+        step_estimate(velocity, end, Root::Upper),
+        peak_step + 1,
+        None,
+        |step| position(step) <= end,
+    );
+    let falling_last = first_step_where(
+        step_estimate(velocity, start, Root::Upper),
+        peak_step + 1,
+        None,
+        |step| position(step) < start,
+    )
+    .map(|overshot| overshot - 1);
+
+    [
+        Steps::new(rising_first, Some(rising_last)),
+        Steps::new(falling_first, falling_last),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[derive(Clone, Copy)]
+enum Root {
+    Lower,
+    Upper,
+}
+
+// Solves `position_after(velocity, step) == position` for `step` using the quadratic formula,
+// returning the step at the peak of the trajectory if the position is never reached.
+#[allow(clippy::cast_precision_loss)]
+fn step_estimate(velocity: i64, position: i64, root: Root) -> f64 {
+    let b = (2 * velocity + 1) as f64;
+    let root_of_discriminant = (b * b - 8.0 * position as f64).max(0.0).sqrt();
+    match root {
+        Root::Lower => f64::midpoint(b, -root_of_discriminant),
+        Root::Upper => f64::midpoint(b, root_of_discriminant),
+    }
+}
+
+// The first step from `first` (up to `last`, if given) at which `reached` is true, where
+// `reached` remains true from then on, or `None` if it is never reached. The estimate is
+// corrected in either direction, since it is subject to rounding errors.
+#[allow(clippy::cast_possible_truncation)]
+fn first_step_where(
+    estimate: f64,
+    first: i64,
+    last: Option<i64>,
+    reached: impl Fn(i64) -> bool,
+) -> Option<i64> {
+    let last_or_max = last.unwrap_or(i64::MAX);
+    if last_or_max < first {
+        return None;
+    }
+    let mut step = (estimate.ceil() as i64).clamp(first, last_or_max);
+    while step > first && reached(step - 1) {
+        step -= 1;
+    }
+    while !reached(step) {
+        if step == last_or_max {
+            return None;
+        }
+        step += 1;
+    }
+    Some(step)
+}
+
+/// Fires the probe with the given velocity, returning the highest y position on its
+/// trajectory if it passes through the target.
+#[must_use]
+pub fn try_shot(
+    target: &Target,
+    x_velocity: i64,
+    y_velocity: i64,
+    physics: &Physics,
+) -> Option<i64> {
+    shoot(target, x_velocity, y_velocity, physics).map(|hit| hit.max_height)
+}
+
+/// A shot which reaches the target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hit {
+    pub velocity: (i64, i64),
+    // The (x, y) position after each step, up to and including the first inside the target.
+    pub trajectory: Vec<(i64, i64)>,
+    // The highest y position on the whole trajectory, including after entering the target.
+    // Without positive gravity the probe may never stop rising, in which case only positions
+    // up to entering the target are considered.
+    pub max_height: i64,
+}
+
+impl Hit {
+    /// The step (counting from 1) on which the probe entered the target.
+    #[must_use]
+    pub fn entry_step(&self) -> usize {
+        self.trajectory.len()
+    }
+}
+
+/// Every shot which reaches the target, in descending order of y velocity. Returns `None` if
+/// there are infinitely many (see `total_velocities_that_reach_target`).
+#[must_use]
+pub fn hits_on_target<'a>(
+    target: &'a Target,
+    physics: &'a Physics,
+) -> Option<impl Iterator<Item = Hit> + 'a> {
+    Some(
+        velocities_that_reach_target(target, physics)?
+            .filter_map(|(x_velocity, y_velocity)| shoot(target, x_velocity, y_velocity, physics)),
+    )
+}
+
+/// Fires the probe with the given velocity, returning its trajectory if it passes through
+/// the target.
+#[must_use]
+pub fn shoot(target: &Target, x_velocity: i64, y_velocity: i64, physics: &Physics) -> Option<Hit> {
+    let (mut x_position, mut y_position) = (0, 0);
+    let mut velocity = (x_velocity, y_velocity);
+    let mut trajectory = Vec::new();
+    let mut max_height = 0;
+
+    loop {
+        x_position += velocity.0;
+        y_position += velocity.1;
+        velocity = physics.next_velocity(velocity);
+        trajectory.push((x_position, y_position));
+        max_height = cmp::max(max_height, y_position);
+
+        if target.x.contains(&x_position) && target.y.contains(&y_position) {
+            // Inside the target, though the probe may still be rising.
+            if physics.gravity > 0 {
+                while velocity.1 > 0 {
+                    y_position += velocity.1;
+                    velocity.1 -= physics.gravity;
+                }
+                max_height = cmp::max(max_height, y_position);
+            }
+            return Some(Hit {
+                velocity: (x_velocity, y_velocity),
+                trajectory,
+                max_height,
+            });
+        } else if physics
+            .max_steps
+            .is_some_and(|max_steps| trajectory.len() >= max_steps)
+            || is_moving_away(target, (x_position, y_position), velocity, physics)
+        {
+            return None;
+        }
+    }
+}
+
+// Whether the probe is past the target and will only continue moving further away from it (or
+// not move at all), in which case there's no point performing more steps. Drag can't change the
+// direction of the probe, so only wind or gravity in the opposite direction could bring it back.
+fn is_moving_away(
+    target: &Target,
+    (x_position, y_position): (i64, i64),
+    (x_velocity, y_velocity): (i64, i64),
+    physics: &Physics,
+) -> bool {
+    (x_velocity <= 0 && physics.wind <= 0 && x_position < *target.x.start())
+        || (x_velocity >= 0 && physics.wind >= 0 && x_position > *target.x.end())
+        || (y_velocity <= 0 && physics.gravity >= 0 && y_position < *target.y.start())
+        || (y_velocity >= 0 && physics.gravity <= 0 && y_position > *target.y.end())
+}
+
+/// Renders the trajectory over the target in the style of the puzzle's diagrams, where `S`
+/// is the launch position, `#` the probe's position after each step and `T` the target.
+#[must_use]
+pub fn render_trajectory(target: &Target, trajectory: &[(i64, i64)]) -> String {
+    let positions: HashSet<(i64, i64)> = trajectory.iter().copied().collect();
+    let (x_range, y_range) = diagram_bounds(target, trajectory);
+    render_cells(y_range.rev(), x_range, |y, x| {
+        if (*x, *y) == (0, 0) {
+            'S'
+        } else if positions.contains(&(*x, *y)) {
+            '#'
+        } else if target.x.contains(x) && target.y.contains(y) {
+            'T'
+        } else {
+            '.'
+        }
+    })
+}
+
+/// Renders the trajectory over the target as an SVG image, with one unit per grid cell.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn render_trajectory_svg(target: &Target, trajectory: &[(i64, i64)]) -> String {
+    let (x_range, y_range) = diagram_bounds(target, trajectory);
+    // SVG coordinates increase downwards, so y coordinates are negated.
+    let (left, top) = (*x_range.start(), -*y_range.end());
+    let width = x_range.end() - x_range.start() + 1;
+    let height = y_range.end() - y_range.start() + 1;
+    let points = iter::once(&(0, 0))
+        .chain(trajectory)
+        .map(|(x, y)| format!("{},{}", x, -y))
+        .collect::<Vec<_>>()
+        .join(" ");
+    [
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {width} {height}">"#,
+            left as f64 - 0.5,
+            top as f64 - 0.5
+        ),
+        format!(
+            r##"  <rect x="{}" y="{}" width="{}" height="{}" fill="#ccc"/>"##,
+            *target.x.start() as f64 - 0.5,
+            -*target.y.end() as f64 - 0.5,
+            target.x.end() - target.x.start() + 1,
+            target.y.end() - target.y.start() + 1
+        ),
+        format!(r#"  <polyline points="{points}" fill="none" stroke="black" stroke-width="0.2"/>"#),
+        String::from(r#"  <circle cx="0" cy="0" r="0.4" fill="red"/>"#),
+        String::from("</svg>"),
+    ]
+    .join("\n")
+}
+
+// The smallest area containing the launch position, the trajectory and the target.
+fn diagram_bounds(
+    target: &Target,
+    trajectory: &[(i64, i64)],
+) -> (RangeInclusive<i64>, RangeInclusive<i64>) {
+    let corners = [
+        (0, 0),
+        (*target.x.start(), *target.y.start()),
+        (*target.x.end(), *target.y.end()),
+    ];
+    let points = || corners.iter().chain(trajectory);
+    let x_coordinates = || points().map(|(x, _)| *x);
+    let y_coordinates = || points().map(|(_, y)| *y);
+    (
+        x_coordinates().min().unwrap_or(0)..=x_coordinates().max().unwrap_or(0),
+        y_coordinates().min().unwrap_or(0)..=y_coordinates().max().unwrap_or(0),
+    )
+}
+
+#[derive(Debug)]
+pub struct Target {
+    pub x: RangeInclusive<i64>,
+    pub y: RangeInclusive<i64>,
+}
+
+impl FromStr for Target {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // eg: "target area: x=20..30, y=-10..-5"
+        let ranges = s
+            .trim()
+            .strip_prefix("target area: ")
+            .ok_or_else(|| ParseError::at(s, s, "\"target area: \""))?;
+        let (x_range, y_range) = ranges
+            .split_once(", ")
+            .ok_or_else(|| ParseError::at(s, ranges, "x and y ranges separated by \", \""))?;
+        Ok(Self {
+            x: parse_range(s, x_range, "x=")?,
+            y: parse_range(s, y_range, "y=")?,
+        })
+    }
+}
+
+fn parse_range<T: FromStr + PartialOrd>(
+    input: &str,
+    text: &str,
+    prefix: &str,
+) -> Result<RangeInclusive<T>, ParseError> {
+    // eg: "x=20..30"
+    let (start, end) = text
+        .strip_prefix(prefix)
+        .and_then(|range| range.split_once(".."))
+        .ok_or_else(|| {
+            ParseError::at(input, text, format!("a range such as \"{prefix}20..30\""))
+        })?;
+    let start: T = parse_value(input, start, "a number")?;
+    let end = parse_value(input, end, "a number")?;
+    if start > end {
+        return Err(ParseError::at(
+            input,
+            text,
+            format!("a range such as \"{prefix}20..30\" (with start <= end)"),
+        ));
+    }
+    Ok(start..=end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn test_try_shot() {
+        assert!(try_shot(
+            &Target {
+                x: 20..=30,
+                y: -10..=-5
+            },
+            7,
+            2,
+            &Physics::default()
+        )
+        .is_some());
+        assert!(try_shot(
+            &Target {
+                x: 20..=30,
+                y: -10..=-5
+            },
+            6,
+            3,
+            &Physics::default()
+        )
+        .is_some());
+        assert!(try_shot(
+            &Target {
+                x: 20..=30,
+                y: -10..=-5
+            },
+            9,
+            0,
+            &Physics::default()
+        )
+        .is_some());
+        assert!(try_shot(
+            &Target {
+                x: 20..=30,
+                y: -10..=-5
+            },
+            17,
+            4,
+            &Physics::default()
+        )
+        .is_none());
+        assert_eq!(
+            try_shot(
+                &Target {
+                    x: 20..=30,
+                    y: -10..=-5
+                },
+                6,
+                9,
+                &Physics::default()
+            ),
+            Some(45)
+        );
+    }
+
+    #[test]
+    fn test_parse_target() {
+        let target = "target area: x=20..30, y=-10..-5"
+            .parse::<Target>()
+            .unwrap();
+        assert_eq!(target.x, 20..=30);
+        assert_eq!(target.y, -10..=-5);
+        let error = "target area: x=20..30, y=-10..z"
+            .parse::<Target>()
+            .unwrap_err();
+        assert_eq!(error.to_string(), r#"1:31: expected a number, found "z""#);
+        let error = "target area: x=30..20, y=-10..-5"
+            .parse::<Target>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"1:14: expected a range such as "x=20..30" (with start <= end), found "x=30..20""#
+        );
+    }
+
+    // Every velocity (up to `speed` in each direction) which reaches the target, in the same
+    // order as `velocities_that_reach_target`.
+    fn brute_force(target: &Target, physics: &Physics, speed: i64) -> Vec<(i64, i64)> {
+        (-speed..=speed)
+            .rev()
+            .flat_map(|y_velocity| (-speed..=speed).map(move |x_velocity| (x_velocity, y_velocity)))
+            .filter(|&(x_velocity, y_velocity)| {
+                try_shot(target, x_velocity, y_velocity, physics).is_some()
+            })
+            .collect()
+    }
+
+    // A target in each quadrant, and ones spanning each axis.
+    fn targets() -> [Target; 7] {
+        [
+            Target {
+                x: 20..=30,
+                y: -10..=-5,
+            },
+            Target {
+                x: -30..=-20,
+                y: -10..=-5,
+            },
+            Target {
+                x: 20..=30,
+                y: 5..=10,
+            },
+            Target {
+                x: -30..=-20,
+                y: 5..=10,
+            },
+            Target {
+                x: -5..=5,
+                y: -10..=-5,
+            },
+            Target {
+                x: 4..=5,
+                y: -4..=9,
+            },
+            Target {
+                x: 0..=3,
+                y: -4..=-1,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_velocities_match_try_shot() {
+        let physics = Physics::default();
+        for target in targets() {
+            let velocities: Vec<(i64, i64)> = velocities_that_reach_target(&target, &physics)
+                .unwrap()
+                .collect();
+            assert_eq!(velocities, brute_force(&target, &physics, 40), "{target:?}");
+        }
+    }
+
+    #[test]
+    fn test_unbounded_target() {
+        // A probe fired with an x velocity of 3 comes to rest at x=6, within the target, after
+        // which every shot fired upwards falls back through y=0 within the target.
+        let target = Target {
+            x: 3..=8,
+            y: -4..=9,
+        };
+        let physics = Physics::default();
+        assert_eq!(try_shot(&target, 3, 1000, &physics), Some(500_500));
+        assert_eq!(max_height_that_reaches_target(&target, &physics), None);
+        assert_eq!(total_velocities_that_reach_target(&target, &physics), None);
+        assert!(hits_on_target(&target, &physics).is_none());
+
+        // Limiting the steps limits how fast shots can be fired upwards.
+        let physics = Physics {
+            max_steps: Some(20),
+            ..physics
+        };
+        let velocities: Vec<(i64, i64)> = velocities_that_reach_target(&target, &physics)
+            .unwrap()
+            .collect();
+        assert_eq!(velocities, brute_force(&target, &physics, 40));
+        assert_eq!(
+            total_velocities_that_reach_target(&target, &physics),
+            Some(velocities.len())
+        );
+    }
+
+    #[test]
+    fn test_targets_in_each_quadrant() {
+        let [below_right, below_left, above_right, above_left, below_centre, ..] = targets();
+        for (target, max_height, total_velocities) in [
+            (below_right, 45, 112),
+            (below_left, 45, 112),
+            (above_right, 55, 103),
+            (above_left, 55, 103),
+            (below_centre, 45, 142),
+        ] {
+            assert_eq!(
+                max_height_that_reaches_target(&target, &Physics::default()),
+                Some(max_height)
+            );
+            assert_eq!(
+                total_velocities_that_reach_target(&target, &Physics::default()),
+                Some(total_velocities)
+            );
+        }
+    }
+
+    #[test]
+    fn test_closed_form_matches_simulation() {
+        let physics = Physics::default();
+        assert!(physics.has_closed_form());
+        for target in targets() {
+            let limits = velocity_limits(&target, &physics).unwrap();
+            let closed_form: Vec<(i64, i64)> =
+                velocities_that_reach_target_closed_form(&target, None, limits).collect();
+            let simulated: Vec<(i64, i64)> =
+                velocities_that_reach_target_simulated(&target, &physics, limits).collect();
+            assert_eq!(closed_form, simulated, "{target:?}");
+        }
+    }
+
+    #[test]
+    fn test_total_velocities_closed_form() {
+        for target in targets() {
+            for max_steps in [None, Some(1), Some(5), Some(12)] {
+                let physics = Physics {
+                    max_steps,
+                    ..Physics::default()
+                };
+                let limits = velocity_limits(&target, &physics).unwrap();
+                assert_eq!(
+                    total_velocities_closed_form(&target, max_steps, limits),
+                    velocities_that_reach_target_closed_form(&target, max_steps, limits).count(),
+                    "{target:?} {max_steps:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_physics() {
+        let target = read_target(example_file()).unwrap();
+        let default = Physics::default();
+        assert_eq!(max_height_that_reaches_target(&target, &default), Some(45));
+        assert_eq!(
+            total_velocities_that_reach_target(&target, &default),
+            Some(112)
+        );
+
+        for (physics, max_height, total_velocities) in [
+            (
+                Physics {
+                    max_steps: Some(5),
+                    ..default
+                },
+                1,
+                96,
+            ),
+            (
+                Physics {
+                    max_steps: Some(7),
+                    ..default
+                },
+                3,
+                98,
+            ),
+            (Physics { drag: 0, ..default }, 45, 108),
+            (Physics { drag: 2, ..default }, 45, 121),
+            (
+                Physics {
+                    gravity: 2,
+                    ..default
+                },
+                20,
+                108,
+            ),
+            (
+                Physics {
+                    gravity: 0,
+                    ..default
+                },
+                0,
+                93,
+            ),
+            (
+                Physics {
+                    gravity: -1,
+                    ..default
+                },
+                0,
+                103,
+            ),
+            (
+                Physics {
+                    gravity: 3,
+                    ..default
+                },
+                145,
+                119,
+            ),
+            (Physics { wind: 1, ..default }, 45, 108),
+            (
+                Physics {
+                    wind: -1,
+                    ..default
+                },
+                45,
+                112,
+            ),
+        ] {
+            assert_eq!(
+                max_height_that_reaches_target(&target, &physics),
+                Some(max_height),
+                "{physics:?}"
+            );
+            assert_eq!(
+                total_velocities_that_reach_target(&target, &physics),
+                Some(total_velocities),
+                "{physics:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_custom_physics_match_try_shot() {
+        let default = Physics::default();
+        for physics in [
+            Physics {
+                gravity: 3,
+                ..default
+            },
+            Physics {
+                gravity: 4,
+                max_steps: Some(12),
+                ..default
+            },
+            Physics {
+                gravity: -2,
+                ..default
+            },
+            Physics { wind: 2, ..default },
+            Physics {
+                drag: 0,
+                wind: -1,
+                ..default
+            },
+            Physics {
+                drag: 3,
+                gravity: 2,
+                wind: 1,
+                ..default
+            },
+        ] {
+            for target in targets().iter().filter(|target| !target.y.contains(&0)) {
+                let velocities: Vec<(i64, i64)> = velocities_that_reach_target(target, &physics)
+                    .unwrap()
+                    .collect();
+                assert_eq!(
+                    velocities,
+                    brute_force(target, &physics, 100),
+                    "{target:?} {physics:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hits_on_target() {
+        let target = read_target(example_file()).unwrap();
+        let hits: Vec<Hit> = hits_on_target(&target, &Physics::default())
+            .unwrap()
+            .collect();
+        assert_eq!(hits.len(), 112);
+        let hit = hits.iter().find(|hit| hit.velocity == (6, 3)).unwrap();
+        assert_eq!(
+            hit.trajectory,
+            [
+                (6, 3),
+                (11, 5),
+                (15, 6),
+                (18, 6),
+                (20, 5),
+                (21, 3),
+                (21, 0),
+                (21, -4),
+                (21, -9)
+            ]
+        );
+        assert_eq!(hit.entry_step(), 9);
+        assert_eq!(shoot(&target, 17, -4, &Physics::default()), None);
+    }
+
+    #[test]
+    fn test_render_trajectory() {
+        let target = read_target(example_file()).unwrap();
+        let hit = shoot(&target, 7, 2, &Physics::default()).unwrap();
+        let expected = indoc! {"
+            .............#....#............
+            .......#..............#........
+            ...............................
+            S........................#.....
+            ...............................
+            ...............................
+            ...........................#...
+            ...............................
+            ....................TTTTTTTTTTT
+            ....................TTTTTTTTTTT
+            ....................TTTTTTTT#TT
+            ....................TTTTTTTTTTT
+            ....................TTTTTTTTTTT
+            ....................TTTTTTTTTTT"};
+        assert_eq!(render_trajectory(&target, &hit.trajectory), expected);
+    }
+
+    #[test]
+    fn test_render_trajectory_svg() {
+        let target = read_target(example_file()).unwrap();
+        let hit = shoot(&target, 7, 2, &Physics::default()).unwrap();
+        let svg = render_trajectory_svg(&target, &hit.trajectory);
+        assert!(svg
+            .starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-0.5 -3.5 31 14">"#));
+        assert!(svg.contains(r##"<rect x="19.5" y="4.5" width="11" height="6" fill="#ccc"/>"##));
+        assert!(svg.contains(r#"<polyline points="0,0 7,-2 13,-3 18,-3 22,-2 25,0 27,3 28,7" "#));
+    }
+
+    #[test]
+    fn test_max_height_large_target() {
+        let target = Target {
+            x: 20_000..=30_000,
+            y: -10_000..=-5_000,
+        };
+        assert_eq!(
+            max_height_that_reaches_target(&target, &Physics::default()),
+            Some(49_995_000)
+        );
+    }
+
+    #[test]
+    fn test_max_height_that_reaches_target() {
+        assert_eq!(
+            max_height_that_reaches_target(
+                &read_target(example_file()).unwrap(),
+                &Physics::default()
+            ),
+            Some(45)
+        );
+    }
+
+    #[test]
+    fn test_total_velocities_that_reach_target() {
+        assert_eq!(
+            total_velocities_that_reach_target(
+                &read_target(example_file()).unwrap(),
+                &Physics::default()
+            ),
+            Some(112)
+        );
+    }
+}
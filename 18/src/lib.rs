@@ -0,0 +1,324 @@
+// Day 18: Snailfish
+// https://adventofcode.com/2021/day/18
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::fmt::{self, Display};
+use std::ops::Add;
+use std::str::FromStr;
+
+use aoc_common::{
+    optional_answer, parse_lines, parse_value, read_and_parse, Error, InputSource, ParseError,
+    Solution,
+};
+use itertools::Itertools;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<SnailfishNumber>;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn summarise_input(numbers: &Self::Input) -> String {
+        format!("{} snailfish numbers", numbers.len())
+    }
+
+    fn part_one(numbers: &Self::Input) -> Self::PartOne {
+        optional_answer(magnitude_of_sum(numbers))
+    }
+
+    fn part_two(numbers: &Self::Input) -> Self::PartTwo {
+        optional_answer(largest_magnitude_of_two(numbers))
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid snailfish number.
+pub fn read_snailfish_numbers(
+    input: impl Into<InputSource>,
+) -> Result<Vec<SnailfishNumber>, Error> {
+    read_and_parse(input, parse_lines)
+}
+
+/// The magnitude of the sum of all of the numbers, or `None` if there are no numbers.
+#[must_use]
+pub fn magnitude_of_sum(numbers: &[SnailfishNumber]) -> Option<u64> {
+    numbers
+        .iter()
+        .cloned()
+        .reduce(|sum, number| sum + number)
+        .map(|sum| sum.magnitude())
+}
+
+/// The largest magnitude from adding any two different numbers (in either order, since
+/// snailfish addition is not commutative), or `None` if there are fewer than two numbers.
+#[must_use]
+pub fn largest_magnitude_of_two(numbers: &[SnailfishNumber]) -> Option<u64> {
+    numbers
+        .iter()
+        .permutations(2)
+        .map(|pair| (pair[0].clone() + pair[1].clone()).magnitude())
+        .max()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnailfishNumber {
+    Regular(u64),
+    Pair(Box<SnailfishNumber>, Box<SnailfishNumber>),
+}
+
+impl SnailfishNumber {
+    #[must_use]
+    pub fn magnitude(&self) -> u64 {
+        match self {
+            SnailfishNumber::Regular(value) => *value,
+            SnailfishNumber::Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+
+    // Repeatedly applies the first possible action (exploding takes priority over
+    // splitting) until neither applies.
+    fn reduce(&mut self) {
+        while self.explode(0).is_some() || self.split() {}
+    }
+
+    // Explodes the leftmost pair nested inside four pairs, returning the values that
+    // still need to be added to the regular numbers to the left and right of it.
+    fn explode(&mut self, depth: usize) -> Option<(Option<u64>, Option<u64>)> {
+        let SnailfishNumber::Pair(left, right) = self else {
+            return None;
+        };
+        if depth >= 4 {
+            if let (SnailfishNumber::Regular(left), SnailfishNumber::Regular(right)) =
+                (left.as_ref(), right.as_ref())
+            {
+                let carried = (Some(*left), Some(*right));
+                *self = SnailfishNumber::Regular(0);
+                return Some(carried);
+            }
+        }
+        if let Some((carry_left, carry_right)) = left.explode(depth + 1) {
+            if let Some(value) = carry_right {
+                right.add_to_leftmost(value);
+            }
+            return Some((carry_left, None));
+        }
+        if let Some((carry_left, carry_right)) = right.explode(depth + 1) {
+            if let Some(value) = carry_left {
+                left.add_to_rightmost(value);
+            }
+            return Some((None, carry_right));
+        }
+        None
+    }
+
+    fn add_to_leftmost(&mut self, value: u64) {
+        match self {
+            SnailfishNumber::Regular(regular) => *regular += value,
+            SnailfishNumber::Pair(left, _) => left.add_to_leftmost(value),
+        }
+    }
+
+    fn add_to_rightmost(&mut self, value: u64) {
+        match self {
+            SnailfishNumber::Regular(regular) => *regular += value,
+            SnailfishNumber::Pair(_, right) => right.add_to_rightmost(value),
+        }
+    }
+
+    // Splits the leftmost regular number that is 10 or greater, returning whether one was found.
+    fn split(&mut self) -> bool {
+        match self {
+            SnailfishNumber::Regular(value) if *value >= 10 => {
+                *self = SnailfishNumber::Pair(
+                    Box::new(SnailfishNumber::Regular(*value / 2)),
+                    Box::new(SnailfishNumber::Regular(value.div_ceil(2))),
+                );
+                true
+            }
+            SnailfishNumber::Regular(_) => false,
+            SnailfishNumber::Pair(left, right) => left.split() || right.split(),
+        }
+    }
+}
+
+impl Add for SnailfishNumber {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut sum = SnailfishNumber::Pair(Box::new(self), Box::new(other));
+        sum.reduce();
+        sum
+    }
+}
+
+impl Display for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnailfishNumber::Regular(value) => write!(f, "{value}"),
+            SnailfishNumber::Pair(left, right) => write!(f, "[{left},{right}]"),
+        }
+    }
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // eg: "[[1,2],3]"
+        let mut rest = s;
+        let number = parse_element(s, &mut rest)?;
+        if !rest.is_empty() {
+            return Err(ParseError::at(s, rest, "the end of the snailfish number"));
+        }
+        Ok(number)
+    }
+}
+
+// Parses either a regular number or a pair from the start of `rest`, advancing past it.
+fn parse_element(input: &str, rest: &mut &str) -> Result<SnailfishNumber, ParseError> {
+    if let Some(after_bracket) = rest.strip_prefix('[') {
+        *rest = after_bracket;
+        let left = parse_element(input, rest)?;
+        expect_char(input, rest, ',')?;
+        let right = parse_element(input, rest)?;
+        expect_char(input, rest, ']')?;
+        Ok(SnailfishNumber::Pair(Box::new(left), Box::new(right)))
+    } else {
+        let digits_end = rest
+            .find(|char: char| !char.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (digits, after_digits) = rest.split_at(digits_end);
+        if digits.is_empty() {
+            return Err(unexpected(input, rest, "a regular number or a pair"));
+        }
+        let value = parse_value(input, digits, "a regular number")?;
+        *rest = after_digits;
+        Ok(SnailfishNumber::Regular(value))
+    }
+}
+
+fn expect_char(input: &str, rest: &mut &str, expected: char) -> Result<(), ParseError> {
+    if let Some(after_char) = rest.strip_prefix(expected) {
+        *rest = after_char;
+        Ok(())
+    } else {
+        Err(unexpected(input, rest, &format!("{expected:?}")))
+    }
+}
+
+// Creates an error located at the next character of `rest` (if any).
+fn unexpected(input: &str, rest: &str, expected: &str) -> ParseError {
+    let next_char_length = rest.chars().next().map_or(0, char::len_utf8);
+    ParseError::at(input, &rest[..next_char_length], expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    fn number(s: &str) -> SnailfishNumber {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        for s in [
+            "[1,2]",
+            "[[1,2],3]",
+            "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]",
+        ] {
+            assert_eq!(number(s).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn parse_snailfish_number_invalid() {
+        let error = "[[1,2],x]".parse::<SnailfishNumber>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"1:8: expected a regular number or a pair, found "x""#
+        );
+        let error = "[1,2".parse::<SnailfishNumber>().unwrap_err();
+        assert_eq!(error.to_string(), "1:5: expected ']', found nothing");
+        let error = "[1,2]]".parse::<SnailfishNumber>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"1:6: expected the end of the snailfish number, found "]""#
+        );
+    }
+
+    #[test]
+    fn explode() {
+        for (before, after) in [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+        ] {
+            let mut snailfish_number = number(before);
+            assert!(snailfish_number.explode(0).is_some());
+            assert_eq!(snailfish_number.to_string(), after);
+        }
+    }
+
+    #[test]
+    fn addition() {
+        let sum = number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]");
+        assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+        let numbers: Vec<SnailfishNumber> =
+            (1..=6).map(|n| number(&format!("[{n},{n}]"))).collect();
+        let sum = numbers
+            .into_iter()
+            .reduce(|sum, number| sum + number)
+            .unwrap();
+        assert_eq!(sum.to_string(), "[[[[5,0],[7,4]],[5,5]],[6,6]]");
+    }
+
+    #[test]
+    fn magnitude() {
+        assert_eq!(number("[[1,2],[[3,4],5]]").magnitude(), 143);
+        assert_eq!(
+            number("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude(),
+            3488
+        );
+    }
+
+    #[test]
+    fn part_one_example() {
+        let numbers = read_snailfish_numbers(example_file()).unwrap();
+        let sum = numbers
+            .into_iter()
+            .reduce(|sum, number| sum + number)
+            .unwrap();
+        assert_eq!(
+            sum.to_string(),
+            "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
+        );
+        assert_eq!(sum.magnitude(), 4140);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let numbers = read_snailfish_numbers(example_file()).unwrap();
+        assert_eq!(largest_magnitude_of_two(&numbers), Some(3993));
+    }
+}
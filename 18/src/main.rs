@@ -0,0 +1,24 @@
+// Day 18: Snailfish
+// https://adventofcode.com/2021/day/18
+
+#![warn(clippy::pedantic)]
+
+use std::process;
+
+use aoc_2021_18::{largest_magnitude_of_two, magnitude_of_sum, read_snailfish_numbers};
+use aoc_common::{optional_answer, InputSource};
+
+fn main() {
+    let numbers = read_snailfish_numbers(InputSource::from_args()).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    println!(
+        "Part 1: Magnitude of the final sum = {}",
+        optional_answer(magnitude_of_sum(&numbers))
+    );
+    println!(
+        "Part 2: Largest magnitude of any sum of two numbers = {}",
+        optional_answer(largest_magnitude_of_two(&numbers))
+    );
+}
@@ -0,0 +1,366 @@
+// Day 19: Beacon Scanner
+// https://adventofcode.com/2021/day/19
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::collections::{HashMap, HashSet};
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use aoc_common::{
+    optional_answer, parse_value, read_and_parse, sections, Error, InputSource, ParseError,
+    Solution,
+};
+use itertools::Itertools;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Vec<Scanner>;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_scanners(input)
+    }
+
+    fn summarise_input(scanners: &Self::Input) -> String {
+        let total_beacons: usize = scanners.iter().map(|scanner| scanner.beacons.len()).sum();
+        format!(
+            "{} scanners, {total_beacons} beacon reports",
+            scanners.len()
+        )
+    }
+
+    fn part_one(scanners: &Self::Input) -> Self::PartOne {
+        optional_answer(align_scanners(scanners).map(|aligned| total_beacons(&aligned)))
+    }
+
+    fn part_two(scanners: &Self::Input) -> Self::PartTwo {
+        optional_answer(align_scanners(scanners).map(|aligned| largest_scanner_distance(&aligned)))
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid scanner report.
+pub fn read_scanners(input: impl Into<InputSource>) -> Result<Vec<Scanner>, Error> {
+    read_and_parse(input, parse_scanners)
+}
+
+/// Parses the reports from each scanner, which are separated by blank lines.
+///
+/// # Errors
+///
+/// Returns an error if any of the scanner reports are invalid.
+pub fn parse_scanners(input: &str) -> Result<Vec<Scanner>, ParseError> {
+    sections(input)
+        .map(|section| {
+            section
+                .parse::<Scanner>()
+                .map_err(|error| error.within(input, section))
+        })
+        .collect()
+}
+
+// Two scanners are only considered to overlap if they detect at least this many beacons in common.
+const MIN_OVERLAPPING_BEACONS: usize = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point {
+    #[must_use]
+    pub fn manhattan_distance(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    fn coordinates(self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // eg: "-618,-824,-621"
+        let (x, y, z) = s
+            .split(',')
+            .collect_tuple()
+            .ok_or_else(|| ParseError::at(s, s, "a beacon position in the form \"x,y,z\""))?;
+        Ok(Point {
+            x: parse_value(s, x, "an x coordinate")?,
+            y: parse_value(s, y, "a y coordinate")?,
+            z: parse_value(s, z, "a z coordinate")?,
+        })
+    }
+}
+
+/// One of the 24 orientations a scanner can be facing, represented as the axis (and its
+/// sign) of the scanner's coordinate system which maps to each axis of the global one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rotation {
+    axes: [usize; 3],
+    signs: [i64; 3],
+}
+
+impl Rotation {
+    /// All 24 rotations, being those signed permutations of the axes which preserve
+    /// handedness (ie: excluding reflections).
+    #[must_use]
+    pub fn all() -> Vec<Rotation> {
+        (0..3)
+            .permutations(3)
+            .cartesian_product((0..3).map(|_| [1, -1]).multi_cartesian_product())
+            .map(|(axes, signs)| Rotation {
+                axes: [axes[0], axes[1], axes[2]],
+                signs: [signs[0], signs[1], signs[2]],
+            })
+            .filter(|rotation| rotation.determinant() == 1)
+            .collect()
+    }
+
+    #[must_use]
+    pub fn apply(self, point: Point) -> Point {
+        let coordinates = point.coordinates();
+        let rotated = |axis: usize| coordinates[self.axes[axis]] * self.signs[axis];
+        Point {
+            x: rotated(0),
+            y: rotated(1),
+            z: rotated(2),
+        }
+    }
+
+    // The determinant of a signed permutation matrix is the product of the signs, negated
+    // if the permutation is odd. Proper rotations have a determinant of 1.
+    fn determinant(self) -> i64 {
+        let inversions = (0..3)
+            .tuple_combinations()
+            .filter(|&(i, j)| self.axes[i] > self.axes[j])
+            .count();
+        let permutation_sign = if inversions % 2 == 0 { 1 } else { -1 };
+        permutation_sign * self.signs.iter().product::<i64>()
+    }
+}
+
+/// The beacons detected by a single scanner, relative to its own position and orientation.
+#[derive(Clone, Debug)]
+pub struct Scanner {
+    pub beacons: Vec<Point>,
+}
+
+impl FromStr for Scanner {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // eg: "--- scanner 0 ---\n404,-588,-901\n528,-643,409"
+        let mut lines = s.lines();
+        let header = lines.next().unwrap_or(s);
+        let is_valid_header = header
+            .strip_prefix("--- scanner ")
+            .and_then(|header| header.strip_suffix(" ---"))
+            .is_some_and(|number| number.parse::<usize>().is_ok());
+        if !is_valid_header {
+            return Err(ParseError::at(
+                s,
+                header,
+                "a scanner header such as \"--- scanner 0 ---\"",
+            ));
+        }
+        let beacons = lines
+            .map(|line| line.parse::<Point>().map_err(|error| error.within(s, line)))
+            .collect::<Result<_, _>>()?;
+        Ok(Scanner { beacons })
+    }
+}
+
+/// A scanner whose position (and the positions of its beacons) are known relative to the
+/// first scanner.
+#[derive(Clone, Debug)]
+pub struct AlignedScanner {
+    pub position: Point,
+    pub beacons: Vec<Point>,
+}
+
+/// Determines the position of every scanner relative to the first, by repeatedly finding
+/// a rotation and offset at which an unaligned scanner shares enough beacons with one that
+/// has already been aligned. Returns `None` if not every scanner overlaps with the others.
+#[must_use]
+pub fn align_scanners(scanners: &[Scanner]) -> Option<Vec<AlignedScanner>> {
+    let (first, others) = scanners.split_first()?;
+    let rotations = Rotation::all();
+    let mut aligned = vec![AlignedScanner {
+        position: Point { x: 0, y: 0, z: 0 },
+        beacons: first.beacons.clone(),
+    }];
+    let mut unaligned: Vec<&Scanner> = others.iter().collect();
+
+    // Each aligned scanner only needs to be compared against each unaligned scanner once.
+    let mut next_reference = 0;
+    while let Some(reference) = aligned.get(next_reference) {
+        let reference_beacons: HashSet<Point> = reference.beacons.iter().copied().collect();
+        let (newly_aligned, still_unaligned): (Vec<_>, Vec<_>) = unaligned
+            .into_iter()
+            .map(|scanner| (scanner, try_align(&reference_beacons, scanner, &rotations)))
+            .partition(|(_, alignment)| alignment.is_some());
+        aligned.extend(
+            newly_aligned
+                .into_iter()
+                .filter_map(|(_, alignment)| alignment),
+        );
+        unaligned = still_unaligned
+            .into_iter()
+            .map(|(scanner, _)| scanner)
+            .collect();
+        next_reference += 1;
+    }
+
+    unaligned.is_empty().then_some(aligned)
+}
+
+fn try_align(
+    reference_beacons: &HashSet<Point>,
+    scanner: &Scanner,
+    rotations: &[Rotation],
+) -> Option<AlignedScanner> {
+    rotations.iter().find_map(|rotation| {
+        let rotated: Vec<Point> = scanner
+            .beacons
+            .iter()
+            .map(|beacon| rotation.apply(*beacon))
+            .collect();
+        // If the scanners overlap, the correct offset will be the difference between the
+        // positions of many pairs of beacons, whereas incorrect offsets will be scattered.
+        let mut offset_counts: HashMap<Point, usize> = HashMap::new();
+        for reference_beacon in reference_beacons {
+            for beacon in &rotated {
+                *offset_counts
+                    .entry(*reference_beacon - *beacon)
+                    .or_insert(0) += 1;
+            }
+        }
+        offset_counts
+            .into_iter()
+            .find(|(_, count)| *count >= MIN_OVERLAPPING_BEACONS)
+            .map(|(offset, _)| AlignedScanner {
+                position: offset,
+                beacons: rotated.iter().map(|beacon| *beacon + offset).collect(),
+            })
+    })
+}
+
+#[must_use]
+pub fn total_beacons(aligned: &[AlignedScanner]) -> usize {
+    aligned
+        .iter()
+        .flat_map(|scanner| &scanner.beacons)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+#[must_use]
+pub fn largest_scanner_distance(aligned: &[AlignedScanner]) -> u64 {
+    aligned
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| a.position.manhattan_distance(b.position))
+        .max()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+        let point = Point { x: 1, y: 2, z: 3 };
+        let rotated: HashSet<Point> = rotations.iter().map(|r| r.apply(point)).collect();
+        assert_eq!(rotated.len(), 24);
+        // A rotation about the z axis.
+        assert!(rotated.contains(&Point { x: -2, y: 1, z: 3 }));
+        // A reflection in the x axis is not a rotation.
+        assert!(!rotated.contains(&Point { x: -1, y: 2, z: 3 }));
+    }
+
+    #[test]
+    fn part_one_example() {
+        let scanners = read_scanners(example_file()).unwrap();
+        let aligned = align_scanners(&scanners).unwrap();
+        let positions: Vec<Point> = aligned.iter().map(|scanner| scanner.position).collect();
+        assert!(positions.contains(&Point {
+            x: 68,
+            y: -1246,
+            z: -43
+        }));
+        assert_eq!(total_beacons(&aligned), 79);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let scanners = read_scanners(example_file()).unwrap();
+        let aligned = align_scanners(&scanners).unwrap();
+        assert_eq!(largest_scanner_distance(&aligned), 3621);
+    }
+
+    #[test]
+    fn non_overlapping_scanners() {
+        let scanners =
+            parse_scanners("--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n4,5,6").unwrap();
+        assert!(align_scanners(&scanners).is_none());
+    }
+
+    #[test]
+    fn parse_scanners_invalid() {
+        let error =
+            parse_scanners("--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n4,x,6").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"5:3: expected a y coordinate, found "x""#
+        );
+        let error = parse_scanners("--- scanner 0 ---\n1,2,3\n\nscanner 1\n4,5,6").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"4:1: expected a scanner header such as "--- scanner 0 ---", found "scanner 1""#
+        );
+    }
+}
@@ -0,0 +1,25 @@
+// Day 19: Beacon Scanner
+// https://adventofcode.com/2021/day/19
+
+#![warn(clippy::pedantic)]
+
+use std::process;
+
+use aoc_2021_19::{align_scanners, largest_scanner_distance, read_scanners, total_beacons};
+use aoc_common::InputSource;
+
+fn main() {
+    let scanners = read_scanners(InputSource::from_args()).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    let aligned = align_scanners(&scanners).unwrap_or_else(|| {
+        eprintln!("Not every scanner overlaps with the others, so they cannot all be aligned!");
+        process::exit(1);
+    });
+    println!("Part 1: Total beacons = {}", total_beacons(&aligned));
+    println!(
+        "Part 2: Largest Manhattan distance between any two scanners = {}",
+        largest_scanner_distance(&aligned)
+    );
+}
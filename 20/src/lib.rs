@@ -0,0 +1,270 @@
+// Day 20: Trench Map
+// https://adventofcode.com/2021/day/20
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use aoc_common::{
+    grid_points, optional_answer, parse_grid, read_and_parse, render_grid, sections, Error,
+    InputSource, ParseError, Solution,
+};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = TrenchMap;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<TrenchMap>()
+    }
+
+    fn summarise_input(trench_map: &Self::Input) -> String {
+        let image = &trench_map.image;
+        format!(
+            "{}x{} image",
+            image.columns.clone().count(),
+            image.rows.clone().count()
+        )
+    }
+
+    fn part_one(trench_map: &Self::Input) -> Self::PartOne {
+        optional_answer(lit_pixels_after_steps(trench_map, 2))
+    }
+
+    fn part_two(trench_map: &Self::Input) -> Self::PartTwo {
+        optional_answer(lit_pixels_after_steps(trench_map, 50))
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or is not a valid trench map.
+pub fn read_trench_map(input: impl Into<InputSource>) -> Result<TrenchMap, Error> {
+    read_and_parse(input, str::parse::<TrenchMap>)
+}
+
+/// The number of lit pixels after enhancing the image `steps` times, or `None` if there are
+/// infinitely many (since the background is lit).
+#[must_use]
+pub fn lit_pixels_after_steps(trench_map: &TrenchMap, steps: usize) -> Option<usize> {
+    (0..steps)
+        .fold(trench_map.image.clone(), |image, _| {
+            image.enhance(&trench_map.algorithm)
+        })
+        .lit_pixel_count()
+}
+
+const ALGORITHM_LENGTH: usize = 512;
+
+// Pixels are identified by (row, column).
+type Point = (i64, i64);
+
+#[derive(Debug)]
+pub struct TrenchMap {
+    pub algorithm: Vec<bool>,
+    pub image: Image,
+}
+
+impl FromStr for TrenchMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sections = sections(s);
+
+        let algorithm_section = sections.next().unwrap_or(s);
+        let algorithm = parse_grid(algorithm_section, "a pixel (# or .)", parse_pixel)
+            .map_err(|error| error.within(s, algorithm_section))?
+            .concat();
+        if algorithm.len() != ALGORITHM_LENGTH {
+            return Err(ParseError::at(
+                s,
+                algorithm_section,
+                format!("an image enhancement algorithm of {ALGORITHM_LENGTH} pixels"),
+            ));
+        }
+
+        let image_section = sections
+            .next()
+            .ok_or_else(|| ParseError::at(s, &s[s.len()..], "an input image"))?;
+        let pixels = parse_grid(image_section, "a pixel (# or .)", parse_pixel)
+            .map_err(|error| error.within(s, image_section))?;
+        let height = i64::try_from(pixels.len()).expect("Image height must fit in an i64");
+        let width = pixels.iter().map(Vec::len).max().unwrap_or_default();
+        let width = i64::try_from(width).expect("Image width must fit in an i64");
+        let lit_pixels = grid_points(pixels)
+            .into_iter()
+            .filter_map(|(point, lit)| lit.then_some(point))
+            .collect();
+
+        Ok(TrenchMap {
+            algorithm,
+            image: Image {
+                lit_pixels,
+                rows: 0..=height - 1,
+                columns: 0..=width - 1,
+                background_lit: false,
+            },
+        })
+    }
+}
+
+fn parse_pixel(char: char) -> Option<bool> {
+    match char {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Image {
+    // Pixels within the bounds are tracked individually, whereas every pixel outside them
+    // (stretching infinitely in all directions) shares the state of the background.
+    lit_pixels: HashSet<Point>,
+    pub rows: RangeInclusive<i64>,
+    pub columns: RangeInclusive<i64>,
+    pub background_lit: bool,
+}
+
+impl Image {
+    #[must_use]
+    pub fn is_lit(&self, (row, column): Point) -> bool {
+        if self.rows.contains(&row) && self.columns.contains(&column) {
+            self.lit_pixels.contains(&(row, column))
+        } else {
+            self.background_lit
+        }
+    }
+
+    /// Applies the enhancement algorithm once. Only pixels adjacent to the current bounds
+    /// can differ from the background afterwards, so the bounds grow by one in each direction.
+    #[must_use]
+    pub fn enhance(&self, algorithm: &[bool]) -> Image {
+        let rows = self.rows.start() - 1..=self.rows.end() + 1;
+        let columns = self.columns.start() - 1..=self.columns.end() + 1;
+        let lit_pixels = rows
+            .clone()
+            .flat_map(|row| columns.clone().map(move |column| (row, column)))
+            .filter(|point| algorithm[self.algorithm_index(*point)])
+            .collect();
+        // Pixels in the background are surrounded by the background, so have an index of
+        // either all zeros or all ones.
+        let background_index = if self.background_lit {
+            ALGORITHM_LENGTH - 1
+        } else {
+            0
+        };
+        Image {
+            lit_pixels,
+            rows,
+            columns,
+            background_lit: algorithm[background_index],
+        }
+    }
+
+    // The index is formed from the 3x3 square of pixels centred on the given pixel, read
+    // left to right and top to bottom as binary digits.
+    fn algorithm_index(&self, (row, column): Point) -> usize {
+        (row - 1..=row + 1)
+            .flat_map(|row| (column - 1..=column + 1).map(move |column| (row, column)))
+            .fold(0, |index, point| {
+                (index << 1) | usize::from(self.is_lit(point))
+            })
+    }
+
+    /// Returns `None` if the background is lit, since there are then infinitely many lit pixels.
+    #[must_use]
+    pub fn lit_pixel_count(&self) -> Option<usize> {
+        (!self.background_lit).then_some(self.lit_pixels.len())
+    }
+
+    /// Renders the pixels within the bounds of the image, using `#` for lit and `.` for dark.
+    #[must_use]
+    pub fn render(&self) -> String {
+        render_grid(
+            self.rows.clone(),
+            self.columns.clone(),
+            |row, column| self.is_lit((*row, *column)),
+            '.',
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn enhance_example() {
+        let trench_map = read_trench_map(example_file()).unwrap();
+        let image = trench_map.image.enhance(&trench_map.algorithm);
+        let expected = "\
+.##.##.
+#..#.#.
+##.#..#
+####..#
+.#..##.
+..##..#
+...#.#.";
+        assert_eq!(image.render(), expected);
+    }
+
+    #[test]
+    fn part_one_example() {
+        let trench_map = read_trench_map(example_file()).unwrap();
+        assert_eq!(lit_pixels_after_steps(&trench_map, 2), Some(35));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let trench_map = read_trench_map(example_file()).unwrap();
+        assert_eq!(lit_pixels_after_steps(&trench_map, 50), Some(3351));
+    }
+
+    #[test]
+    fn flipping_background() {
+        // An algorithm that lights dark pixels and darkens lit ones, regardless of neighbours,
+        // since only the centre pixel (with a value of 16) is considered.
+        let algorithm: String = (0..ALGORITHM_LENGTH)
+            .map(|index| if index & 16 == 0 { '#' } else { '.' })
+            .collect();
+        let trench_map = format!("{algorithm}\n\n#.\n..")
+            .parse::<TrenchMap>()
+            .unwrap();
+        let image = trench_map.image.enhance(&trench_map.algorithm);
+        assert!(image.background_lit);
+        assert_eq!(image.lit_pixel_count(), None);
+        assert_eq!(image.render(), "####\n#.##\n####\n####");
+        assert_eq!(lit_pixels_after_steps(&trench_map, 2), Some(1));
+    }
+
+    #[test]
+    fn parse_trench_map_invalid() {
+        let error = "#.#\n\n#.\n..".parse::<TrenchMap>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r##"1:1: expected an image enhancement algorithm of 512 pixels, found "#.#""##
+        );
+        let algorithm = ".".repeat(ALGORITHM_LENGTH);
+        let error = format!("{algorithm}\n\n#.\n.x")
+            .parse::<TrenchMap>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"4:2: expected a pixel (# or .), found "x""#
+        );
+    }
+}
@@ -0,0 +1,24 @@
+// Day 20: Trench Map
+// https://adventofcode.com/2021/day/20
+
+#![warn(clippy::pedantic)]
+
+use std::process;
+
+use aoc_2021_20::{lit_pixels_after_steps, read_trench_map};
+use aoc_common::{optional_answer, InputSource};
+
+fn main() {
+    let trench_map = read_trench_map(InputSource::from_args()).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    println!(
+        "Part 1: Lit pixels after 2 steps = {}",
+        optional_answer(lit_pixels_after_steps(&trench_map, 2))
+    );
+    println!(
+        "Part 2: Lit pixels after 50 steps = {}",
+        optional_answer(lit_pixels_after_steps(&trench_map, 50))
+    );
+}
@@ -0,0 +1,204 @@
+// Day 21: Dirac Dice
+// https://adventofcode.com/2021/day/21
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::collections::HashMap;
+
+use aoc_common::{parse_value, read_and_parse, Error, InputSource, ParseError, Solution};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = [u8; 2];
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_starting_positions(input)
+    }
+
+    fn summarise_input(starting_positions: &Self::Input) -> String {
+        format!(
+            "starting positions {} and {}",
+            starting_positions[0], starting_positions[1]
+        )
+    }
+
+    fn part_one(starting_positions: &Self::Input) -> Self::PartOne {
+        deterministic_game_result(*starting_positions)
+    }
+
+    fn part_two(starting_positions: &Self::Input) -> Self::PartTwo {
+        let [player_one_wins, player_two_wins] = quantum_game_wins(*starting_positions);
+        player_one_wins.max(player_two_wins)
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or does not contain both starting positions.
+pub fn read_starting_positions(input: impl Into<InputSource>) -> Result<[u8; 2], Error> {
+    read_and_parse(input, parse_starting_positions)
+}
+
+/// Parses the starting position of each player, which must be listed in order.
+///
+/// # Errors
+///
+/// Returns an error if there aren't exactly two players, or a position isn't on the board.
+pub fn parse_starting_positions(input: &str) -> Result<[u8; 2], ParseError> {
+    let mut lines = input.lines();
+    let mut positions = [0; 2];
+    for (index, position) in positions.iter_mut().enumerate() {
+        // eg: "Player 1 starting position: 4"
+        let prefix = format!("Player {} starting position: ", index + 1);
+        let line = lines.next().unwrap_or(&input[input.len()..]);
+        let value = line
+            .strip_prefix(&prefix)
+            .ok_or_else(|| ParseError::at(input, line, format!("\"{prefix}\"")))?;
+        *position = parse_value(input, value, "a position from 1 to 10")?;
+        if !(1..=BOARD_SIZE).contains(position) {
+            return Err(ParseError::at(input, value, "a position from 1 to 10"));
+        }
+    }
+    if let Some(line) = lines.next() {
+        return Err(ParseError::at(input, line, "the end of the input"));
+    }
+    Ok(positions)
+}
+
+const BOARD_SIZE: u8 = 10;
+
+// Spaces are numbered from 1 to 10, wrapping back around to 1 after 10.
+fn advance(position: u8, spaces: u8) -> u8 {
+    (position - 1 + spaces % BOARD_SIZE) % BOARD_SIZE + 1
+}
+
+/// Plays with a deterministic 100-sided die (which rolls 1, 2, 3, ... 100, 1, ...) until a
+/// player reaches 1000, returning the losing player's score multiplied by the number of rolls.
+#[must_use]
+pub fn deterministic_game_result(starting_positions: [u8; 2]) -> u64 {
+    const WINNING_SCORE: u64 = 1000;
+    let mut positions = starting_positions;
+    let mut scores = [0; 2];
+    let mut die = (1..=100_u8).cycle();
+    let mut rolls = 0;
+
+    for player in [0, 1].into_iter().cycle() {
+        // Only the number of spaces modulo the board size affects the outcome.
+        let spaces: u8 = die.by_ref().take(3).map(|roll| roll % BOARD_SIZE).sum();
+        rolls += 3;
+        positions[player] = advance(positions[player], spaces);
+        scores[player] += u64::from(positions[player]);
+        if scores[player] >= WINNING_SCORE {
+            return scores[1 - player] * rolls;
+        }
+    }
+    unreachable!("The players take turns forever until one wins")
+}
+
+// The game state at the start of a turn in the quantum game.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    positions: [u8; 2],
+    scores: [u8; 2],
+    player_to_move: usize,
+}
+
+/// Plays with a three-sided Dirac die, which splits the universe into one copy per outcome
+/// every time it is rolled, until a player reaches 21. Returns the number of universes in
+/// which each player wins.
+///
+/// Rather than simulating every universe, the win counts from each distinct game state are
+/// calculated once and memoised, since many different sequences of rolls reach the same state.
+#[must_use]
+pub fn quantum_game_wins(starting_positions: [u8; 2]) -> [u64; 2] {
+    let state = State {
+        positions: starting_positions,
+        scores: [0; 2],
+        player_to_move: 0,
+    };
+    wins_from_state(state, &mut HashMap::new())
+}
+
+fn wins_from_state(state: State, cache: &mut HashMap<State, [u64; 2]>) -> [u64; 2] {
+    const WINNING_SCORE: u8 = 21;
+    // The number of ways each total can be rolled, using three rolls of the die.
+    const ROLL_FREQUENCIES: [(u8, u64); 7] =
+        [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+    if let Some(wins) = cache.get(&state) {
+        return *wins;
+    }
+    let player = state.player_to_move;
+    let mut wins = [0; 2];
+    for (spaces, frequency) in ROLL_FREQUENCIES {
+        let mut next = state;
+        next.positions[player] = advance(state.positions[player], spaces);
+        next.scores[player] += next.positions[player];
+        if next.scores[player] >= WINNING_SCORE {
+            wins[player] += frequency;
+        } else {
+            next.player_to_move = 1 - player;
+            let next_wins = wins_from_state(next, cache);
+            wins[0] += frequency * next_wins[0];
+            wins[1] += frequency * next_wins[1];
+        }
+    }
+    cache.insert(state, wins);
+    wins
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn advance_wraps() {
+        assert_eq!(advance(7, 5), 2);
+        assert_eq!(advance(8, 2), 10);
+        assert_eq!(advance(10, 13), 3);
+    }
+
+    #[test]
+    fn part_one_example() {
+        let starting_positions = read_starting_positions(example_file()).unwrap();
+        assert_eq!(starting_positions, [4, 8]);
+        assert_eq!(deterministic_game_result(starting_positions), 739_785);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let starting_positions = read_starting_positions(example_file()).unwrap();
+        assert_eq!(
+            quantum_game_wins(starting_positions),
+            [444_356_092_776_315, 341_960_390_180_808]
+        );
+    }
+
+    #[test]
+    fn parse_starting_positions_invalid() {
+        let error = parse_starting_positions(
+            "Player 1 starting position: 4\nPlayer 2 starting position: 11",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"2:29: expected a position from 1 to 10, found "11""#
+        );
+        let error = parse_starting_positions("Player 2 starting position: 4").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"1:1: expected "Player 1 starting position: ", found "Player 2 starting position: 4""#
+        );
+    }
+}
@@ -0,0 +1,26 @@
+// Day 21: Dirac Dice
+// https://adventofcode.com/2021/day/21
+
+#![warn(clippy::pedantic)]
+
+use std::process;
+
+use aoc_2021_21::{deterministic_game_result, quantum_game_wins, read_starting_positions};
+use aoc_common::InputSource;
+
+fn main() {
+    let starting_positions =
+        read_starting_positions(InputSource::from_args()).unwrap_or_else(|error| {
+            eprintln!("{error}");
+            process::exit(1);
+        });
+    println!(
+        "Part 1: Losing score multiplied by die rolls = {}",
+        deterministic_game_result(starting_positions)
+    );
+    let [player_one_wins, player_two_wins] = quantum_game_wins(starting_positions);
+    println!(
+        "Part 2: Universes won by the player who wins most = {}",
+        player_one_wins.max(player_two_wins)
+    );
+}
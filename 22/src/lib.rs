@@ -0,0 +1,311 @@
+// Day 22: Reactor Reboot
+// https://adventofcode.com/2021/day/22
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use aoc_common::{
+    optional_answer, parse_lines, parse_value, read_and_parse, Error, InputSource, ParseError,
+    Solution,
+};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Vec<Step>;
+    type PartOne = u128;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn summarise_input(steps: &Self::Input) -> String {
+        format!("{} reboot steps", steps.len())
+    }
+
+    fn part_one(steps: &Self::Input) -> Self::PartOne {
+        lit_cubes_in_initialization_region(steps)
+    }
+
+    fn part_two(steps: &Self::Input) -> Self::PartTwo {
+        optional_answer(lit_cubes(steps))
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid reboot step.
+pub fn read_steps(input: impl Into<InputSource>) -> Result<Vec<Step>, Error> {
+    read_and_parse(input, parse_lines)
+}
+
+/// The number of cubes left on within the region -50..=50 in each axis, after all steps.
+///
+/// # Panics
+///
+/// Panics if the signed volumes sum to a negative total (as with `lit_cubes`).
+#[must_use]
+pub fn lit_cubes_in_initialization_region(steps: &[Step]) -> u128 {
+    let region = Cuboid {
+        x: -50..=50,
+        y: -50..=50,
+        z: -50..=50,
+    };
+    let clipped_steps: Vec<Step> = steps
+        .iter()
+        .filter_map(|step| {
+            step.cuboid.intersection(&region).map(|cuboid| Step {
+                on: step.on,
+                cuboid,
+            })
+        })
+        .collect();
+    lit_cubes(&clipped_steps).expect("The initialization region is small enough to count")
+}
+
+/// The number of cubes left on after all steps.
+///
+/// Rather than tracking individual cubes, a list of cuboids with signed volumes is maintained,
+/// whose sum is the number of lit cubes. For each step, every overlap with an existing cuboid
+/// is cancelled out by adding their intersection with the opposite sign, after which the step's
+/// cuboid is added if it is being turned on.
+///
+/// Returns `None` if the cuboids are too large for their volumes to be summed in an `i128`.
+///
+/// # Panics
+///
+/// Panics if the signed volumes sum to a negative total, which can only happen due to a bug.
+#[must_use]
+pub fn lit_cubes(steps: &[Step]) -> Option<u128> {
+    let mut signed_cuboids: Vec<(Cuboid, i64)> = Vec::new();
+    for step in steps {
+        let overlaps: Vec<(Cuboid, i64)> = signed_cuboids
+            .iter()
+            .filter_map(|(cuboid, sign)| {
+                cuboid
+                    .intersection(&step.cuboid)
+                    .map(|intersection| (intersection, -sign))
+            })
+            .collect();
+        signed_cuboids.extend(overlaps);
+        if step.on {
+            signed_cuboids.push((step.cuboid.clone(), 1));
+        }
+    }
+    let total = signed_cuboids
+        .iter()
+        .try_fold(0_i128, |total, (cuboid, sign)| {
+            let volume = i128::try_from(cuboid.volume()?).ok()?;
+            total.checked_add(i128::from(*sign) * volume)
+        })?;
+    Some(u128::try_from(total).expect("The signed volumes should never sum to a negative total"))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cuboid {
+    pub x: RangeInclusive<i64>,
+    pub y: RangeInclusive<i64>,
+    pub z: RangeInclusive<i64>,
+}
+
+impl Cuboid {
+    /// The number of cubes within the cuboid, or `None` if there are too many to fit in a `u128`
+    /// (which is possible, since each axis can be up to 2^64 cubes long).
+    #[must_use]
+    pub fn volume(&self) -> Option<u128> {
+        [&self.x, &self.y, &self.z]
+            .iter()
+            .try_fold(1_u128, |volume, range| {
+                volume.checked_mul(u128::from(range.end().abs_diff(*range.start())) + 1)
+            })
+    }
+
+    /// The cuboid shared by both, or `None` if they don't overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        Some(Cuboid {
+            x: range_intersection(&self.x, &other.x)?,
+            y: range_intersection(&self.y, &other.y)?,
+            z: range_intersection(&self.z, &other.z)?,
+        })
+    }
+}
+
+fn range_intersection(
+    a: &RangeInclusive<i64>,
+    b: &RangeInclusive<i64>,
+) -> Option<RangeInclusive<i64>> {
+    let start = *a.start().max(b.start());
+    let end = *a.end().min(b.end());
+    (start <= end).then_some(start..=end)
+}
+
+#[derive(Debug)]
+pub struct Step {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+impl FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // eg: "on x=-20..26,y=-36..17,z=-47..7"
+        let (state, ranges) = s.split_once(' ').ok_or_else(|| {
+            ParseError::at(s, s, "a reboot step such as \"on x=1..2,y=3..4,z=5..6\"")
+        })?;
+        let on = match state {
+            "on" => true,
+            "off" => false,
+            _ => return Err(ParseError::at(s, state, "\"on\" or \"off\"")),
+        };
+        let mut ranges = ranges.split(',');
+        let mut next_range = |prefix| {
+            let text = ranges.next().unwrap_or(&s[s.len()..]);
+            parse_range(s, text, prefix)
+        };
+        let cuboid = Cuboid {
+            x: next_range("x=")?,
+            y: next_range("y=")?,
+            z: next_range("z=")?,
+        };
+        if let Some(extra) = ranges.next() {
+            return Err(ParseError::at(s, extra, "the end of the reboot step"));
+        }
+        Ok(Step { on, cuboid })
+    }
+}
+
+fn parse_range(input: &str, text: &str, prefix: &str) -> Result<RangeInclusive<i64>, ParseError> {
+    // eg: "x=10..12"
+    let expected = format!("a range such as \"{prefix}10..12\"");
+    let (start, end) = text
+        .strip_prefix(prefix)
+        .and_then(|range| range.split_once(".."))
+        .ok_or_else(|| ParseError::at(input, text, &expected))?;
+    let start: i64 = parse_value(input, start, "a number")?;
+    let end = parse_value(input, end, "a number")?;
+    if start > end {
+        return Err(ParseError::at(
+            input,
+            text,
+            format!("{expected} (with start <= end)"),
+        ));
+    }
+    Ok(start..=end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    // Counts the lit cubes by tracking each one individually, for comparison.
+    fn lit_cubes_naive(steps: &[Step]) -> u128 {
+        let mut lit = HashSet::new();
+        for step in steps {
+            for x in step.cuboid.x.clone() {
+                for y in step.cuboid.y.clone() {
+                    for z in step.cuboid.z.clone() {
+                        if step.on {
+                            lit.insert((x, y, z));
+                        } else {
+                            lit.remove(&(x, y, z));
+                        }
+                    }
+                }
+            }
+        }
+        u128::try_from(lit.len()).unwrap()
+    }
+
+    #[test]
+    fn part_one_example() {
+        let steps = read_steps(example_file()).unwrap();
+        assert_eq!(lit_cubes_in_initialization_region(&steps), 39);
+        assert_eq!(lit_cubes_naive(&steps), 39);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let steps = read_steps(example_file()).unwrap();
+        assert_eq!(lit_cubes(&steps), Some(39));
+    }
+
+    // The larger example includes steps far outside the initialization region.
+    #[test]
+    fn part_two_reboot_example() {
+        let steps =
+            read_steps(Path::new(env!("CARGO_MANIFEST_DIR")).join("example-reboot.txt")).unwrap();
+        assert_eq!(lit_cubes_in_initialization_region(&steps), 474_140);
+        assert_eq!(lit_cubes(&steps), Some(2_758_514_936_282_235));
+    }
+
+    #[test]
+    fn steps_outside_initialization_region() {
+        let steps: Vec<Step> = parse_lines(
+            "on x=-100..100,y=0..0,z=0..0\non x=40..60,y=-2..2,z=0..1\noff x=45..55,y=0..0,z=0..1",
+        )
+        .unwrap();
+        assert_eq!(lit_cubes_in_initialization_region(&steps), 188);
+        assert_eq!(lit_cubes(&steps), Some(lit_cubes_naive(&steps)));
+    }
+
+    #[test]
+    fn cuboids_spanning_entire_axes() {
+        let steps: Vec<Step> = parse_lines(&format!(
+            "on x={}..{},y=0..0,z=0..0\noff x=0..0,y=0..0,z=0..0",
+            i64::MIN,
+            i64::MAX
+        ))
+        .unwrap();
+        assert_eq!(lit_cubes(&steps), Some(2_u128.pow(64) - 1));
+        assert_eq!(lit_cubes_in_initialization_region(&steps), 100);
+
+        let steps: Vec<Step> = parse_lines(&format!(
+            "on x=0..{max},y=0..{max},z=0..0\non x=-1..{max},y=-1..{max},z=0..0",
+            max = i64::MAX
+        ))
+        .unwrap();
+        assert_eq!(lit_cubes(&steps), Some((2_u128.pow(63) + 1).pow(2)));
+
+        let steps: Vec<Step> = parse_lines(&format!(
+            "on x={min}..{max},y={min}..{max},z={min}..{max}",
+            min = i64::MIN,
+            max = i64::MAX
+        ))
+        .unwrap();
+        assert_eq!(steps[0].cuboid.volume(), None);
+        assert_eq!(lit_cubes(&steps), None);
+    }
+
+    #[test]
+    fn parse_step_invalid() {
+        let error = "on x=1..2,y=3..4,q=5..6".parse::<Step>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"1:18: expected a range such as "z=10..12", found "q=5..6""#
+        );
+        let error = "toggle x=1..2,y=3..4,z=5..6".parse::<Step>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"1:1: expected "on" or "off", found "toggle""#
+        );
+        let error = "off x=1..2,y=4..3,z=5..6".parse::<Step>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"1:12: expected a range such as "y=10..12" (with start <= end), found "y=4..3""#
+        );
+    }
+}
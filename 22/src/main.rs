@@ -0,0 +1,24 @@
+// Day 22: Reactor Reboot
+// https://adventofcode.com/2021/day/22
+
+#![warn(clippy::pedantic)]
+
+use std::process;
+
+use aoc_2021_22::{lit_cubes, lit_cubes_in_initialization_region, read_steps};
+use aoc_common::{optional_answer, InputSource};
+
+fn main() {
+    let steps = read_steps(InputSource::from_args()).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    println!(
+        "Part 1: Lit cubes in the initialization region = {}",
+        lit_cubes_in_initialization_region(&steps)
+    );
+    println!(
+        "Part 2: Lit cubes in the whole reactor = {}",
+        optional_answer(lit_cubes(&steps))
+    );
+}
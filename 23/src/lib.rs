@@ -0,0 +1,409 @@
+// Day 23: Amphipod
+// https://adventofcode.com/2021/day/23
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_common::{optional_answer, read_and_parse, Error, InputSource, ParseError, Solution};
+use priority_queue::PriorityQueue;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Burrow;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<Burrow>()
+    }
+
+    fn summarise_input(burrow: &Self::Input) -> String {
+        format!("{}-deep rooms", burrow.depth)
+    }
+
+    fn part_one(burrow: &Self::Input) -> Self::PartOne {
+        optional_answer(least_energy_to_organise(burrow))
+    }
+
+    fn part_two(burrow: &Self::Input) -> Self::PartTwo {
+        optional_answer(
+            burrow
+                .unfolded()
+                .and_then(|burrow| least_energy_to_organise(&burrow)),
+        )
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or is not a valid burrow diagram.
+pub fn read_burrow(input: impl Into<InputSource>) -> Result<Burrow, Error> {
+    read_and_parse(input, str::parse::<Burrow>)
+}
+
+const HALLWAY_LENGTH: usize = 11;
+const ROOMS: usize = 4;
+const MAX_DEPTH: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    const ALL: [Amphipod; ROOMS] = [
+        Amphipod::Amber,
+        Amphipod::Bronze,
+        Amphipod::Copper,
+        Amphipod::Desert,
+    ];
+
+    #[must_use]
+    pub fn energy_per_step(self) -> u32 {
+        match self {
+            Amphipod::Amber => 1,
+            Amphipod::Bronze => 10,
+            Amphipod::Copper => 100,
+            Amphipod::Desert => 1000,
+        }
+    }
+
+    // Each type of amphipod belongs in the room with the same index (from left to right).
+    fn destination_room(self) -> usize {
+        self as usize
+    }
+
+    fn from_char(char: char) -> Option<Amphipod> {
+        match char {
+            'A' => Some(Amphipod::Amber),
+            'B' => Some(Amphipod::Bronze),
+            'C' => Some(Amphipod::Copper),
+            'D' => Some(Amphipod::Desert),
+            _ => None,
+        }
+    }
+}
+
+// The hallway position directly outside each room.
+fn room_entrance(room: usize) -> usize {
+    2 + 2 * room
+}
+
+fn is_room_entrance(position: usize) -> bool {
+    (0..ROOMS).any(|room| room_entrance(room) == position)
+}
+
+/// The positions of all of the amphipods, which is small and cheap to hash, so that it can be
+/// used as a node in the search. Rooms are stored from top to bottom, with only the first
+/// `depth` spaces of each in use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Burrow {
+    pub hallway: [Option<Amphipod>; HALLWAY_LENGTH],
+    pub rooms: [[Option<Amphipod>; MAX_DEPTH]; ROOMS],
+    pub depth: usize,
+}
+
+impl Burrow {
+    /// The burrow with the two extra rows from the folded section of the diagram inserted
+    /// between the existing rows, or `None` if the rooms aren't 2-deep.
+    #[must_use]
+    pub fn unfolded(&self) -> Option<Burrow> {
+        use Amphipod::{Amber as A, Bronze as B, Copper as C, Desert as D};
+        const EXTRA_ROWS: [[Amphipod; ROOMS]; 2] = [[D, C, B, A], [D, B, A, C]];
+
+        if self.depth != 2 {
+            return None;
+        }
+        let mut unfolded = *self;
+        unfolded.depth = 4;
+        for (room, spaces) in unfolded.rooms.iter_mut().enumerate() {
+            *spaces = [
+                self.rooms[room][0],
+                Some(EXTRA_ROWS[0][room]),
+                Some(EXTRA_ROWS[1][room]),
+                self.rooms[room][1],
+            ];
+        }
+        Some(unfolded)
+    }
+
+    #[must_use]
+    pub fn is_organised(&self) -> bool {
+        Amphipod::ALL.iter().all(|amphipod| {
+            self.rooms[amphipod.destination_room()][..self.depth]
+                .iter()
+                .all(|space| *space == Some(*amphipod))
+        })
+    }
+
+    // A room can only be entered once it contains no amphipods of other types.
+    fn can_enter_room(&self, amphipod: Amphipod) -> bool {
+        self.rooms[amphipod.destination_room()][..self.depth]
+            .iter()
+            .flatten()
+            .all(|occupant| *occupant == amphipod)
+    }
+
+    // Whether the hallway between the two positions is empty, excluding `from` itself.
+    fn is_hallway_clear(&self, from: usize, to: usize) -> bool {
+        let between = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+        self.hallway[between].iter().all(Option::is_none)
+    }
+
+    /// Every burrow reachable with a single legal move, along with the energy that move
+    /// uses. Amphipods in the hallway may only move into their own room, and amphipods in
+    /// a room may only move out into the hallway (but never stop outside a room).
+    #[must_use]
+    pub fn moves(&self) -> Vec<(Burrow, u32)> {
+        let mut moves = Vec::new();
+
+        for (position, amphipod) in self.hallway.iter().enumerate() {
+            let Some(amphipod) = *amphipod else {
+                continue;
+            };
+            let room = amphipod.destination_room();
+            let entrance = room_entrance(room);
+            if !self.can_enter_room(amphipod) || !self.is_hallway_clear(position, entrance) {
+                continue;
+            }
+            let Some(space) = self.rooms[room][..self.depth]
+                .iter()
+                .rposition(Option::is_none)
+            else {
+                continue;
+            };
+            let mut next = *self;
+            next.hallway[position] = None;
+            next.rooms[room][space] = Some(amphipod);
+            let steps = position.abs_diff(entrance) + space + 1;
+            moves.push((next, energy(amphipod, steps)));
+        }
+
+        for room in 0..ROOMS {
+            let spaces = &self.rooms[room][..self.depth];
+            let Some((space, amphipod)) = spaces
+                .iter()
+                .enumerate()
+                .find_map(|(space, occupant)| occupant.map(|amphipod| (space, amphipod)))
+            else {
+                continue;
+            };
+            // Amphipods that are already in their own room (with none of another type
+            // below them) never need to leave.
+            if spaces[space..].iter().all(|occupant| {
+                occupant.is_some_and(|occupant| occupant.destination_room() == room)
+            }) {
+                continue;
+            }
+            let entrance = room_entrance(room);
+            for position in (0..HALLWAY_LENGTH).filter(|position| !is_room_entrance(*position)) {
+                if !self.is_hallway_clear(entrance, position) {
+                    continue;
+                }
+                let mut next = *self;
+                next.rooms[room][space] = None;
+                next.hallway[position] = Some(amphipod);
+                let steps = space + 1 + entrance.abs_diff(position);
+                moves.push((next, energy(amphipod, steps)));
+            }
+        }
+
+        moves
+    }
+}
+
+fn energy(amphipod: Amphipod, steps: usize) -> u32 {
+    amphipod.energy_per_step() * u32::try_from(steps).expect("Steps must fit in a u32")
+}
+
+/// The least total energy required to move every amphipod into its own room, found using
+/// Dijkstra's algorithm over the possible states of the burrow. Returns `None` if the
+/// amphipods can't be organised.
+#[must_use]
+pub fn least_energy_to_organise(burrow: &Burrow) -> Option<u32> {
+    let mut energy_from_source: HashMap<Burrow, u32> = HashMap::new();
+    // As with Day 15, the queue is only populated with states as they are reached.
+    let mut queue = PriorityQueue::new();
+
+    energy_from_source.insert(*burrow, 0);
+    queue.push(*burrow, Reverse(0));
+
+    while let Some((state, Reverse(total_energy))) = queue.pop() {
+        if state.is_organised() {
+            return Some(total_energy);
+        }
+
+        for (next_state, move_energy) in state.moves() {
+            let new_total_energy = total_energy + move_energy;
+            let existing_total_energy = energy_from_source.get(&next_state).unwrap_or(&u32::MAX);
+
+            if new_total_energy < *existing_total_energy {
+                energy_from_source.insert(next_state, new_total_energy);
+                queue.push_increase(next_state, Reverse(new_total_energy));
+            }
+        }
+    }
+
+    None
+}
+
+impl FromStr for Burrow {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // eg: "#############\n#...........#\n###B#C#B#D###\n  #A#D#C#A#\n  #########"
+        let mut lines = s.lines();
+        let end_of_input = &s[s.len()..];
+
+        let top_wall = lines.next().unwrap_or(end_of_input);
+        if top_wall != "#############" {
+            return Err(ParseError::at(
+                s,
+                top_wall,
+                "the top wall \"#############\"",
+            ));
+        }
+
+        let hallway_line = lines.next().unwrap_or(end_of_input);
+        let mut hallway = [None; HALLWAY_LENGTH];
+        for (position, space) in hallway.iter_mut().enumerate() {
+            *space = parse_space(s, hallway_line, position + 1)?;
+        }
+
+        let mut rooms = [[None; MAX_DEPTH]; ROOMS];
+        let mut depth = 0;
+        loop {
+            let line = lines.next().unwrap_or(end_of_input);
+            if line.trim() == "#########" && depth > 0 {
+                break;
+            }
+            if depth == MAX_DEPTH {
+                return Err(ParseError::at(s, line, "the bottom wall \"#########\""));
+            }
+            for (room, spaces) in rooms.iter_mut().enumerate() {
+                let column = room_entrance(room) + 1;
+                let space = parse_space(s, line, column)?;
+                // Amphipods can't pass each other, so a room can't have an empty space below an
+                // amphipod (which moves would otherwise treat as reachable).
+                if space.is_none() && depth > 0 && spaces[depth - 1].is_some() {
+                    return Err(ParseError::at(
+                        s,
+                        line.get(column..=column).unwrap_or(line),
+                        "an amphipod, since the space above it is occupied",
+                    ));
+                }
+                spaces[depth] = space;
+            }
+            depth += 1;
+        }
+
+        if let Some(line) = lines.next() {
+            return Err(ParseError::at(s, line, "the end of the burrow diagram"));
+        }
+        Ok(Burrow {
+            hallway,
+            rooms,
+            depth,
+        })
+    }
+}
+
+fn parse_space(input: &str, line: &str, column: usize) -> Result<Option<Amphipod>, ParseError> {
+    let text = line.get(column..=column).unwrap_or(line);
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some('.'), None) => Ok(None),
+        (Some(char), None) if Amphipod::from_char(char).is_some() => Ok(Amphipod::from_char(char)),
+        _ => Err(ParseError::at(
+            input,
+            text,
+            "an amphipod (A, B, C or D) or an empty space (.)",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn parse_burrow() {
+        let burrow = read_burrow(example_file()).unwrap();
+        assert_eq!(burrow.depth, 2);
+        assert_eq!(burrow.hallway, [None; HALLWAY_LENGTH]);
+        assert_eq!(
+            burrow.rooms[1][..2],
+            [Some(Amphipod::Copper), Some(Amphipod::Desert)]
+        );
+        assert!(!burrow.is_organised());
+    }
+
+    #[test]
+    fn moves_from_hallway() {
+        let burrow = "#############\n#.....D.....#\n###A#B#C#.###\n  #A#B#C#D#\n  #########"
+            .parse::<Burrow>()
+            .unwrap();
+        let organised = "#############\n#...........#\n###A#B#C#D###\n  #A#B#C#D#\n  #########"
+            .parse::<Burrow>()
+            .unwrap();
+        assert!(organised.is_organised());
+        assert_eq!(burrow.moves(), [(organised, 4000)]);
+    }
+
+    #[test]
+    fn part_one_example() {
+        let burrow = read_burrow(example_file()).unwrap();
+        assert_eq!(least_energy_to_organise(&burrow), Some(12521));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let burrow = read_burrow(example_file()).unwrap().unfolded().unwrap();
+        assert_eq!(least_energy_to_organise(&burrow), Some(44169));
+    }
+
+    #[test]
+    fn unsolvable_burrow() {
+        let burrow = "#############\n#...........#\n###A#A#C#D###\n  #A#B#C#D#\n  #########"
+            .parse::<Burrow>()
+            .unwrap();
+        assert_eq!(least_energy_to_organise(&burrow), None);
+    }
+
+    #[test]
+    fn parse_burrow_invalid() {
+        let error = "#############\n#...........#\n###B#C#E#D###\n  #A#D#C#A#\n  #########"
+            .parse::<Burrow>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"3:8: expected an amphipod (A, B, C or D) or an empty space (.), found "E""#
+        );
+        let error = "#############\n#...........#\n###A#.#C#D###\n  #.#B#C#D#\n  #########"
+            .parse::<Burrow>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"4:4: expected an amphipod, since the space above it is occupied, found ".""#
+        );
+    }
+}
@@ -0,0 +1,28 @@
+// Day 23: Amphipod
+// https://adventofcode.com/2021/day/23
+
+#![warn(clippy::pedantic)]
+
+use std::process;
+
+use aoc_2021_23::{least_energy_to_organise, read_burrow};
+use aoc_common::{optional_answer, InputSource};
+
+fn main() {
+    let burrow = read_burrow(InputSource::from_args()).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    println!(
+        "Part 1: Least energy to organise the amphipods = {}",
+        optional_answer(least_energy_to_organise(&burrow))
+    );
+    println!(
+        "Part 2: Least energy to organise the unfolded burrow = {}",
+        optional_answer(
+            burrow
+                .unfolded()
+                .and_then(|burrow| least_energy_to_organise(&burrow))
+        )
+    );
+}
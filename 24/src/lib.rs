@@ -0,0 +1,473 @@
+// Day 24: Arithmetic Logic Unit
+// https://adventofcode.com/2021/day/24
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use aoc_common::{
+    optional_answer, parse_lines, parse_value, read_and_parse, Error, InputSource, ParseError,
+    Solution,
+};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Instruction>;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn summarise_input(program: &Self::Input) -> String {
+        format!("{} instructions", program.len())
+    }
+
+    fn part_one(program: &Self::Input) -> Self::PartOne {
+        optional_answer(largest_model_number(program))
+    }
+
+    fn part_two(program: &Self::Input) -> Self::PartTwo {
+        optional_answer(smallest_model_number(program))
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or contains an invalid instruction.
+pub fn read_program(input: impl Into<InputSource>) -> Result<Vec<Instruction>, Error> {
+    read_and_parse(input, parse_lines)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl FromStr for Register {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "w" => Ok(Register::W),
+            "x" => Ok(Register::X),
+            "y" => Ok(Register::Y),
+            "z" => Ok(Register::Z),
+            _ => Err(ParseError::at(s, s, "a register (w, x, y or z)")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Number(i64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Input(Register),
+    Add(Register, Operand),
+    Multiply(Register, Operand),
+    Divide(Register, Operand),
+    Modulo(Register, Operand),
+    Equal(Register, Operand),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // eg: "inp w" or "add x -1"
+        let mut parts = s.split(' ');
+        let operation = parts.next().unwrap_or(s);
+        let mut next_part = || parts.next().unwrap_or(&s[s.len()..]);
+        let register = next_part();
+        let register = register
+            .parse::<Register>()
+            .map_err(|error| error.within(s, register))?;
+        let instruction = if operation == "inp" {
+            Instruction::Input(register)
+        } else {
+            let operand = next_part();
+            let operand = match operand.parse::<Register>() {
+                Ok(register) => Operand::Register(register),
+                Err(_) => Operand::Number(parse_value(s, operand, "a register or a number")?),
+            };
+            match operation {
+                "add" => Instruction::Add(register, operand),
+                "mul" => Instruction::Multiply(register, operand),
+                "div" => Instruction::Divide(register, operand),
+                "mod" => Instruction::Modulo(register, operand),
+                "eql" => Instruction::Equal(register, operand),
+                _ => {
+                    return Err(ParseError::at(
+                        s,
+                        operation,
+                        "an operation (inp, add, mul, div, mod or eql)",
+                    ));
+                }
+            }
+        };
+        if let Some(extra) = parts.next() {
+            return Err(ParseError::at(s, extra, "the end of the instruction"));
+        }
+        Ok(instruction)
+    }
+}
+
+/// An invalid operation encountered whilst running an ALU program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AluError {
+    MissingInput,
+    DivideByZero,
+    InvalidModulo { a: i64, b: i64 },
+    Overflow,
+}
+
+impl Display for AluError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AluError::MissingInput => write!(f, "Program read more input than was provided"),
+            AluError::DivideByZero => write!(f, "Program attempted to divide by zero"),
+            AluError::InvalidModulo { a, b } => {
+                write!(f, "Program attempted to calculate {a} mod {b}")
+            }
+            AluError::Overflow => write!(f, "Program overflowed a 64-bit register"),
+        }
+    }
+}
+
+impl error::Error for AluError {}
+
+/// The four registers of the arithmetic logic unit, all of which start at zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Alu {
+    registers: [i64; 4],
+}
+
+impl Alu {
+    #[must_use]
+    pub fn register(&self, register: Register) -> i64 {
+        self.registers[register as usize]
+    }
+
+    fn operand(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.register(register),
+            Operand::Number(number) => number,
+        }
+    }
+
+    /// Runs the program, with each `inp` instruction reading the next value from `inputs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the program runs out of input, attempts an invalid division or
+    /// modulo (which would crash the real ALU), or overflows a register.
+    pub fn run(
+        &mut self,
+        program: &[Instruction],
+        inputs: impl IntoIterator<Item = i64>,
+    ) -> Result<(), AluError> {
+        let mut inputs = inputs.into_iter();
+        for instruction in program {
+            let (register, value) = match *instruction {
+                Instruction::Input(register) => {
+                    (register, inputs.next().ok_or(AluError::MissingInput)?)
+                }
+                Instruction::Add(register, operand) => (
+                    register,
+                    self.register(register)
+                        .checked_add(self.operand(operand))
+                        .ok_or(AluError::Overflow)?,
+                ),
+                Instruction::Multiply(register, operand) => (
+                    register,
+                    self.register(register)
+                        .checked_mul(self.operand(operand))
+                        .ok_or(AluError::Overflow)?,
+                ),
+                Instruction::Divide(register, operand) => {
+                    let b = self.operand(operand);
+                    if b == 0 {
+                        return Err(AluError::DivideByZero);
+                    }
+                    // Division truncates towards zero, as with Rust's integer division.
+                    // Dividing the minimum value by -1 is the only case that can overflow.
+                    (
+                        register,
+                        self.register(register)
+                            .checked_div(b)
+                            .ok_or(AluError::Overflow)?,
+                    )
+                }
+                Instruction::Modulo(register, operand) => {
+                    let (a, b) = (self.register(register), self.operand(operand));
+                    if a < 0 || b <= 0 {
+                        return Err(AluError::InvalidModulo { a, b });
+                    }
+                    (register, a % b)
+                }
+                Instruction::Equal(register, operand) => (
+                    register,
+                    i64::from(self.register(register) == self.operand(operand)),
+                ),
+            };
+            self.registers[register as usize] = value;
+        }
+        Ok(())
+    }
+}
+
+const MODEL_NUMBER_DIGITS: usize = 14;
+const BLOCK_LENGTH: usize = 18;
+
+/// The parameters which differ between each of the 14 blocks of the MONAD program, one per
+/// digit of the model number. Every block is otherwise identical, and treats `z` as a stack
+/// of base 26 values:
+///
+/// - Blocks with a divisor of 1 always push `digit + push_offset`, since their check offset
+///   is too large for the check to ever succeed.
+/// - Blocks with a divisor of 26 pop the top value, and push again unless
+///   `digit == popped + check_offset`.
+///
+/// So for `z` to end up as zero, every popping block must pass its check, which pairs each
+/// popping digit with the pushing digit whose value it popped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MonadBlock {
+    pub divisor: i64,
+    pub check_offset: i64,
+    pub push_offset: i64,
+}
+
+impl MonadBlock {
+    #[must_use]
+    pub fn instructions(self) -> Vec<Instruction> {
+        use Instruction::{Add, Divide, Equal, Input, Modulo, Multiply};
+        use Operand::{Number, Register as R};
+        use Register::{W, X, Y, Z};
+        vec![
+            Input(W),
+            Multiply(X, Number(0)),
+            Add(X, R(Z)),
+            Modulo(X, Number(26)),
+            Divide(Z, Number(self.divisor)),
+            Add(X, Number(self.check_offset)),
+            Equal(X, R(W)),
+            Equal(X, Number(0)),
+            Multiply(Y, Number(0)),
+            Add(Y, Number(25)),
+            Multiply(Y, R(X)),
+            Add(Y, Number(1)),
+            Multiply(Z, R(Y)),
+            Multiply(Y, Number(0)),
+            Add(Y, R(W)),
+            Add(Y, Number(self.push_offset)),
+            Multiply(Y, R(X)),
+            Add(Z, R(Y)),
+        ]
+    }
+}
+
+/// Splits a MONAD program into its blocks, returning `None` if it doesn't consist of exactly
+/// 14 blocks of the expected form.
+#[must_use]
+pub fn analyse_monad(program: &[Instruction]) -> Option<Vec<MonadBlock>> {
+    let blocks: Vec<&[Instruction]> = program.chunks(BLOCK_LENGTH).collect();
+    if blocks.len() != MODEL_NUMBER_DIGITS {
+        return None;
+    }
+    blocks
+        .into_iter()
+        .map(|instructions| {
+            let number_at = |index| match instructions.get(index) {
+                Some(
+                    Instruction::Divide(_, Operand::Number(number))
+                    | Instruction::Add(_, Operand::Number(number)),
+                ) => Some(*number),
+                _ => None,
+            };
+            let block = MonadBlock {
+                divisor: number_at(4)?,
+                check_offset: number_at(5)?,
+                push_offset: number_at(15)?,
+            };
+            (block.instructions() == instructions).then_some(block)
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy)]
+enum Preference {
+    Largest,
+    Smallest,
+}
+
+#[must_use]
+pub fn largest_model_number(program: &[Instruction]) -> Option<u64> {
+    find_model_number(program, Preference::Largest)
+}
+
+#[must_use]
+pub fn smallest_model_number(program: &[Instruction]) -> Option<u64> {
+    find_model_number(program, Preference::Smallest)
+}
+
+// Each pair of pushing and popping digits must differ by a fixed amount, so the digits of
+// each pair can be chosen independently, as the largest (or smallest) that satisfy it.
+fn find_model_number(program: &[Instruction], preference: Preference) -> Option<u64> {
+    let blocks = analyse_monad(program)?;
+    let mut digits = [0; MODEL_NUMBER_DIGITS];
+    let mut pushed: Vec<(usize, i64)> = Vec::new();
+
+    for (index, block) in blocks.iter().enumerate() {
+        match block.divisor {
+            1 if block.check_offset > 9 => pushed.push((index, block.push_offset)),
+            26 => {
+                let (pushed_index, push_offset) = pushed.pop()?;
+                // The popping digit must equal the pushing digit plus this difference.
+                let difference = push_offset + block.check_offset;
+                let pushed_digit = match preference {
+                    Preference::Largest => 9.min(9 - difference),
+                    Preference::Smallest => 1.max(1 - difference),
+                };
+                let popped_digit = pushed_digit + difference;
+                if !(1..=9).contains(&pushed_digit) || !(1..=9).contains(&popped_digit) {
+                    return None;
+                }
+                digits[pushed_index] = pushed_digit;
+                digits[index] = popped_digit;
+            }
+            _ => return None,
+        }
+    }
+    if !pushed.is_empty() {
+        return None;
+    }
+
+    // Confirm the analysis by running the program itself.
+    let mut alu = Alu::default();
+    alu.run(program, digits).ok()?;
+    (alu.register(Register::Z) == 0).then(|| {
+        digits
+            .iter()
+            .fold(0, |number, digit| number * 10 + digit.unsigned_abs())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    // The example is a MONAD program generated from arbitrary (but valid) block parameters.
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    fn run(program: &str, inputs: &[i64]) -> Result<Alu, AluError> {
+        let program: Vec<Instruction> = parse_lines(program).unwrap();
+        let mut alu = Alu::default();
+        alu.run(&program, inputs.iter().copied())?;
+        Ok(alu)
+    }
+
+    #[test]
+    fn alu_negate() {
+        let alu = run("inp x\nmul x -1", &[7]).unwrap();
+        assert_eq!(alu.register(Register::X), -7);
+    }
+
+    #[test]
+    fn alu_three_times_larger() {
+        let program = "inp z\ninp x\nmul z 3\neql z x";
+        assert_eq!(run(program, &[2, 6]).unwrap().register(Register::Z), 1);
+        assert_eq!(run(program, &[2, 5]).unwrap().register(Register::Z), 0);
+    }
+
+    #[test]
+    fn alu_binary() {
+        let program = "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\ndiv w 2\nmod w 2";
+        let alu = run(program, &[11]).unwrap();
+        assert_eq!(
+            [Register::W, Register::X, Register::Y, Register::Z].map(|r| alu.register(r)),
+            [1, 0, 1, 1]
+        );
+    }
+
+    #[test]
+    fn alu_errors() {
+        assert_eq!(run("inp w\ninp x", &[1]), Err(AluError::MissingInput));
+        assert_eq!(run("div w x", &[]), Err(AluError::DivideByZero));
+        assert_eq!(
+            run("add w -3\nmod w 2", &[]),
+            Err(AluError::InvalidModulo { a: -3, b: 2 })
+        );
+        assert_eq!(
+            run("add w 9223372036854775807\nadd w 1", &[]),
+            Err(AluError::Overflow)
+        );
+        assert_eq!(
+            run("add w 9223372036854775807\nmul w 2", &[]),
+            Err(AluError::Overflow)
+        );
+        assert_eq!(
+            run("add w -9223372036854775808\ndiv w -1", &[]),
+            Err(AluError::Overflow)
+        );
+        // Division truncates towards zero.
+        assert_eq!(
+            run("add w -7\ndiv w 2", &[]).unwrap().register(Register::W),
+            -3
+        );
+    }
+
+    #[test]
+    fn part_one_example() {
+        let program = read_program(example_file()).unwrap();
+        assert_eq!(largest_model_number(&program), Some(37_984_959_599_599));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let program = read_program(example_file()).unwrap();
+        assert_eq!(smallest_model_number(&program), Some(11_211_615_155_137));
+    }
+
+    #[test]
+    fn not_a_monad_program() {
+        let program: Vec<Instruction> = parse_lines("inp w\nadd z w").unwrap();
+        assert_eq!(analyse_monad(&program), None);
+        assert_eq!(largest_model_number(&program), None);
+    }
+
+    #[test]
+    fn parse_instruction_invalid() {
+        let error = parse_lines::<Instruction>("inp w\nadd x q").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"2:7: expected a register or a number, found "q""#
+        );
+        let error = parse_lines::<Instruction>("sub x 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"1:1: expected an operation (inp, add, mul, div, mod or eql), found "sub""#
+        );
+        let error = parse_lines::<Instruction>("inp a").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"1:5: expected a register (w, x, y or z), found "a""#
+        );
+    }
+}
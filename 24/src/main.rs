@@ -0,0 +1,24 @@
+// Day 24: Arithmetic Logic Unit
+// https://adventofcode.com/2021/day/24
+
+#![warn(clippy::pedantic)]
+
+use std::process;
+
+use aoc_2021_24::{largest_model_number, read_program, smallest_model_number};
+use aoc_common::{optional_answer, InputSource};
+
+fn main() {
+    let program = read_program(InputSource::from_args()).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    println!(
+        "Part 1: Largest model number accepted by MONAD = {}",
+        optional_answer(largest_model_number(&program))
+    );
+    println!(
+        "Part 2: Smallest model number accepted by MONAD = {}",
+        optional_answer(smallest_model_number(&program))
+    );
+}
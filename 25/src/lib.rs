@@ -0,0 +1,243 @@
+// Day 25: Sea Cucumber
+// https://adventofcode.com/2021/day/25
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+use std::str::FromStr;
+
+use aoc_common::{
+    optional_answer, parse_grid, read_and_parse, render_cells, Error, InputSource, ParseError,
+    Solution,
+};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = SeaFloor;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<SeaFloor>()
+    }
+
+    fn summarise_input(sea_floor: &Self::Input) -> String {
+        format!("{}x{} sea floor", sea_floor.width(), sea_floor.height())
+    }
+
+    fn part_one(sea_floor: &Self::Input) -> Self::PartOne {
+        optional_answer(first_step_without_movement(sea_floor))
+    }
+
+    // Day 25 only has a single puzzle.
+    fn part_two(_sea_floor: &Self::Input) -> Self::PartTwo {
+        String::from("none")
+    }
+}
+
+/// # Errors
+///
+/// Returns an error if the input cannot be read or is not a valid map of the sea floor.
+pub fn read_sea_floor(input: impl Into<InputSource>) -> Result<SeaFloor, Error> {
+    read_and_parse(input, str::parse::<SeaFloor>)
+}
+
+/// The number of the first step (counting from 1) on which no sea cucumbers move, or `None`
+/// if the sea cucumbers keep moving forever.
+#[must_use]
+pub fn first_step_without_movement(sea_floor: &SeaFloor) -> Option<usize> {
+    let mut sea_floor = sea_floor.clone();
+    // If the sea cucumbers never stop moving, then the sea floor must eventually repeat a
+    // previous state (since there are finitely many). This is detected using Brent's
+    // algorithm, which compares against a checkpoint state that is moved forwards at
+    // doubling intervals, rather than storing every state seen so far.
+    let mut checkpoint = sea_floor.clone();
+    let mut checkpoint_interval = 1;
+    let mut steps_since_checkpoint = 0;
+    let mut steps = 1;
+    while sea_floor.step() {
+        if sea_floor == checkpoint {
+            return None;
+        }
+        steps += 1;
+        steps_since_checkpoint += 1;
+        if steps_since_checkpoint == checkpoint_interval {
+            checkpoint = sea_floor.clone();
+            checkpoint_interval *= 2;
+            steps_since_checkpoint = 0;
+        }
+    }
+    Some(steps)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Herd {
+    East,
+    South,
+}
+
+/// The sea floor, whose edges wrap around, such that sea cucumbers which move off one edge
+/// reappear on the opposite edge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeaFloor {
+    cells: Vec<Vec<Option<Herd>>>,
+}
+
+impl SeaFloor {
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.cells.first().map_or(0, Vec::len)
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Moves the east-facing herd and then the south-facing herd, returning whether any sea
+    /// cucumbers moved. Within each herd, every sea cucumber checks whether the space in front
+    /// of it is empty before any of them move.
+    pub fn step(&mut self) -> bool {
+        let moved_east = self.move_herd(Herd::East);
+        let moved_south = self.move_herd(Herd::South);
+        moved_east || moved_south
+    }
+
+    fn move_herd(&mut self, herd: Herd) -> bool {
+        let (width, height) = (self.width(), self.height());
+        let destination = |row: usize, column: usize| match herd {
+            Herd::East => (row, (column + 1) % width),
+            Herd::South => ((row + 1) % height, column),
+        };
+        let moves: Vec<((usize, usize), (usize, usize))> = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .filter(|&(row, column)| self.cells[row][column] == Some(herd))
+            .map(|(row, column)| ((row, column), destination(row, column)))
+            .filter(|&(_, (row, column))| self.cells[row][column].is_none())
+            .collect();
+        for &((from_row, from_column), (to_row, to_column)) in &moves {
+            self.cells[from_row][from_column] = None;
+            self.cells[to_row][to_column] = Some(herd);
+        }
+        !moves.is_empty()
+    }
+
+    /// Renders the sea floor in the same format as the puzzle input, which is useful for
+    /// inspecting the state after a given step.
+    #[must_use]
+    pub fn render(&self) -> String {
+        render_cells(&self.cells, 0..self.width(), |row, column| {
+            match row[*column] {
+                Some(Herd::East) => '>',
+                Some(Herd::South) => 'v',
+                None => '.',
+            }
+        })
+    }
+}
+
+impl FromStr for SeaFloor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cells = parse_grid(
+            s,
+            "a sea cucumber (> or v) or an empty space (.)",
+            |char| match char {
+                '>' => Some(Some(Herd::East)),
+                'v' => Some(Some(Herd::South)),
+                '.' => Some(None),
+                _ => None,
+            },
+        )?;
+        let sea_floor = SeaFloor { cells };
+        let width = sea_floor.width();
+        if let Some(line) = s.lines().find(|line| line.chars().count() != width) {
+            return Err(ParseError::at(s, line, format!("a row of {width} cells")));
+        }
+        Ok(sea_floor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use std::path::{Path, PathBuf};
+
+    fn example_file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("example.txt")
+    }
+
+    #[test]
+    fn step_single_row() {
+        let mut sea_floor = "...>>>>>...".parse::<SeaFloor>().unwrap();
+        assert!(sea_floor.step());
+        assert_eq!(sea_floor.render(), "...>>>>.>..");
+        assert!(sea_floor.step());
+        assert_eq!(sea_floor.render(), "...>>>.>.>.");
+    }
+
+    #[test]
+    fn step_wraps_around() {
+        let mut sea_floor = "..v\n>..\n...".parse::<SeaFloor>().unwrap();
+        sea_floor.step();
+        assert_eq!(sea_floor.render(), "...\n.>v\n...");
+        let mut sea_floor = ">.\nv.".parse::<SeaFloor>().unwrap();
+        sea_floor.step();
+        // The east-facing herd moves first, making room for the south-facing one.
+        assert_eq!(sea_floor.render(), "v>\n..");
+    }
+
+    #[test]
+    fn step_example() {
+        let mut sea_floor = read_sea_floor(example_file()).unwrap();
+        sea_floor.step();
+        let expected = indoc! {"
+            ....>.>v.>
+            v.v>.>v.v.
+            >v>>..>v..
+            >>v>v>.>.v
+            .>v.v...v.
+            v>>.>vvv..
+            ..v...>>..
+            vv...>>vv.
+            >.v.v..v.v"};
+        assert_eq!(sea_floor.render(), expected);
+    }
+
+    #[test]
+    fn part_one_example() {
+        let sea_floor = read_sea_floor(example_file()).unwrap();
+        assert_eq!(first_step_without_movement(&sea_floor), Some(58));
+    }
+
+    #[test]
+    fn never_stops_moving() {
+        let sea_floor = ">.".parse::<SeaFloor>().unwrap();
+        assert_eq!(first_step_without_movement(&sea_floor), None);
+        let sea_floor = ">..\n.v.\n...".parse::<SeaFloor>().unwrap();
+        assert_eq!(first_step_without_movement(&sea_floor), None);
+        let sea_floor = ">>.".parse::<SeaFloor>().unwrap();
+        assert_eq!(first_step_without_movement(&sea_floor), None);
+        let sea_floor = ">>>".parse::<SeaFloor>().unwrap();
+        assert_eq!(first_step_without_movement(&sea_floor), Some(1));
+    }
+
+    #[test]
+    fn parse_sea_floor_invalid() {
+        let error = "..>\n.^.".parse::<SeaFloor>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"2:2: expected a sea cucumber (> or v) or an empty space (.), found "^""#
+        );
+        let error = "..>\n..".parse::<SeaFloor>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"2:1: expected a row of 3 cells, found "..""#
+        );
+    }
+}
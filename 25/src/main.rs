@@ -0,0 +1,20 @@
+// Day 25: Sea Cucumber
+// https://adventofcode.com/2021/day/25
+
+#![warn(clippy::pedantic)]
+
+use std::process;
+
+use aoc_2021_25::{first_step_without_movement, read_sea_floor};
+use aoc_common::{optional_answer, InputSource};
+
+fn main() {
+    let sea_floor = read_sea_floor(InputSource::from_args()).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    println!(
+        "Part 1: First step on which no sea cucumbers move = {}",
+        optional_answer(first_step_without_movement(&sea_floor))
+    );
+}
@@ -0,0 +1,84 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc_common::{ParseError, Solution};
+
+/// Timings for each stage of a solution, over repeated runs against the same input.
+pub struct Benchmark {
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    // There must be at least one duration.
+    fn from_durations(mut durations: Vec<Duration>) -> Self {
+        durations.sort_unstable();
+        let middle = durations.len() / 2;
+        let median = if durations.len().is_multiple_of(2) {
+            (durations[middle - 1] + durations[middle]) / 2
+        } else {
+            durations[middle]
+        };
+        Self {
+            min: durations[0],
+            median,
+            max: durations[durations.len() - 1],
+        }
+    }
+}
+
+pub fn benchmark<S: Solution>(input: &str, iterations: usize) -> Result<Benchmark, ParseError> {
+    let mut parse_durations = Vec::with_capacity(iterations);
+    let mut part_one_durations = Vec::with_capacity(iterations);
+    let mut part_two_durations = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let (parsed, elapsed) = time(|| S::parse(black_box(input)));
+        let parsed = parsed?;
+        parse_durations.push(elapsed);
+        part_one_durations.push(time(|| S::part_one(&parsed)).1);
+        part_two_durations.push(time(|| S::part_two(&parsed)).1);
+    }
+
+    Ok(Benchmark {
+        iterations,
+        parse: Stats::from_durations(parse_durations),
+        part_one: Stats::from_durations(part_one_durations),
+        part_two: Stats::from_durations(part_two_durations),
+    })
+}
+
+// The result is passed through `black_box()` so the work can't be optimised away.
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (result, start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_from_durations() {
+        let stats = Stats::from_durations([5, 1, 3].map(Duration::from_millis).to_vec());
+        assert_eq!(
+            (stats.min, stats.median, stats.max),
+            (
+                Duration::from_millis(1),
+                Duration::from_millis(3),
+                Duration::from_millis(5)
+            )
+        );
+        let stats = Stats::from_durations([4, 1, 2, 8].map(Duration::from_millis).to_vec());
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+}
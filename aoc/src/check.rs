@@ -0,0 +1,74 @@
+use std::fs;
+
+use serde::Deserialize;
+
+use crate::days::{Answers, Day};
+
+// The expected answers for a day's `input.txt`, which are recorded in `answers.toml`
+// alongside it, so that refactoring can't silently change the results.
+#[derive(Deserialize)]
+struct RecordedAnswers {
+    part_one: String,
+    part_two: String,
+}
+
+fn read_recorded_answers(day: &Day) -> Result<RecordedAnswers, String> {
+    let path = day.directory().join("answers.toml");
+    let contents = fs::read_to_string(&path)
+        .map_err(|error| format!("Error reading {}: {error}", path.display()))?;
+    toml::from_str(&contents).map_err(|error| format!("Error parsing {}: {error}", path.display()))
+}
+
+/// Compares the answers for a day's `input.txt` against those recorded in its `answers.toml`,
+/// returning a description of each problem found.
+pub fn check_answers(day: &Day, answers: &Answers) -> Vec<String> {
+    let recorded = match read_recorded_answers(day) {
+        Ok(recorded) => recorded,
+        Err(error) => return vec![format!("Day {:02}: {error}", day.number)],
+    };
+    [
+        (1, &answers.part_one.value, &recorded.part_one),
+        (2, &answers.part_two.value, &recorded.part_two),
+    ]
+    .into_iter()
+    .filter(|(_, actual, expected)| actual != expected)
+    .map(|(part, actual, expected)| {
+        format!(
+            "Day {:02} part {part}: expected {expected:?}, found {actual:?}",
+            day.number
+        )
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{Answer, DAYS};
+    use std::time::Duration;
+
+    #[test]
+    fn recorded_answers_match() {
+        for day in DAYS.iter().filter(|day| day.has_default_input()) {
+            let answers = day.default_input().read_and_parse(day.solve).unwrap();
+            assert_eq!(check_answers(day, &answers), Vec::<String>::new());
+        }
+    }
+
+    #[test]
+    fn check_answers_reports_mismatches() {
+        let answer = |value: &str| Answer {
+            value: value.to_string(),
+            elapsed: Duration::ZERO,
+        };
+        let answers = Answers {
+            input_summary: String::new(),
+            part_one: answer("1292"),
+            part_two: answer("1263"),
+        };
+        assert_eq!(
+            check_answers(&DAYS[0], &answers),
+            [r#"Day 01 part 2: expected "1262", found "1263""#]
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_common::{InputSource, ParseError, Solution};
+
+use crate::bench::{benchmark, time, Benchmark};
+
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str) -> Result<Answers, ParseError>,
+    pub benchmark: fn(&str, usize) -> Result<Benchmark, ParseError>,
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            solve: solve::<S>,
+            benchmark: benchmark::<S>,
+        }
+    }
+
+    // Each day's files (such as `09/input.txt`) live alongside its crate.
+    pub fn directory(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("{:02}", self.number))
+    }
+
+    pub fn default_input(&self) -> InputSource {
+        InputSource::File(self.default_input_path())
+    }
+
+    // Not every day has its puzzle input checked in, in which case it can only be
+    // run against an input file (or stdin) that is explicitly specified.
+    pub fn has_default_input(&self) -> bool {
+        self.default_input_path().exists()
+    }
+
+    fn default_input_path(&self) -> PathBuf {
+        self.directory().join("input.txt")
+    }
+}
+
+pub struct Answers {
+    pub input_summary: String,
+    pub part_one: Answer,
+    pub part_two: Answer,
+}
+
+pub struct Answer {
+    pub value: String,
+    pub elapsed: Duration,
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<aoc_2021_01::Day01>(),
+    Day::new::<aoc_2021_02::Day02>(),
+    Day::new::<aoc_2021_03::Day03>(),
+    Day::new::<aoc_2021_04::Day04>(),
+    Day::new::<aoc_2021_05::Day05>(),
+    Day::new::<aoc_2021_06::Day06>(),
+    Day::new::<aoc_2021_07::Day07>(),
+    Day::new::<aoc_2021_08::Day08>(),
+    Day::new::<aoc_2021_09::Day09>(),
+    Day::new::<aoc_2021_10::Day10>(),
+    Day::new::<aoc_2021_11::Day11>(),
+    Day::new::<aoc_2021_12::Day12>(),
+    Day::new::<aoc_2021_13::Day13>(),
+    Day::new::<aoc_2021_14::Day14>(),
+    Day::new::<aoc_2021_15::Day15>(),
+    Day::new::<aoc_2021_16::Day16>(),
+    Day::new::<aoc_2021_17::Day17>(),
+    Day::new::<aoc_2021_18::Day18>(),
+    Day::new::<aoc_2021_19::Day19>(),
+    Day::new::<aoc_2021_20::Day20>(),
+    Day::new::<aoc_2021_21::Day21>(),
+    Day::new::<aoc_2021_22::Day22>(),
+    Day::new::<aoc_2021_23::Day23>(),
+    Day::new::<aoc_2021_24::Day24>(),
+    Day::new::<aoc_2021_25::Day25>(),
+];
+
+fn solve<S: Solution>(input: &str) -> Result<Answers, ParseError> {
+    let input = S::parse(input)?;
+    let (part_one, part_one_elapsed) = time(|| S::part_one(&input));
+    let (part_two, part_two_elapsed) = time(|| S::part_two(&input));
+    Ok(Answers {
+        input_summary: S::summarise_input(&input),
+        part_one: Answer {
+            value: part_one.to_string(),
+            elapsed: part_one_elapsed,
+        },
+        part_two: Answer {
+            value: part_two.to_string(),
+            elapsed: part_two_elapsed,
+        },
+    })
+}
@@ -0,0 +1,140 @@
+// Advent of Code 2021 runner
+// Solves one or all of the days, printing the answers as a table (or as JSON). Usage:
+//   aoc run <day> [input-file] [--format table|json]   (use `-` to read the input from stdin)
+//   aoc run <day|all> --check   (compares the answers for input.txt against answers.toml)
+//   aoc bench <day|all> [--iterations <count>]   (reports min/median/max of each stage)
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+mod bench;
+mod check;
+mod days;
+mod output;
+
+use std::env;
+use std::process;
+
+use aoc_common::InputSource;
+use bench::Benchmark;
+use check::check_answers;
+use days::{Answers, Day, DAYS};
+use output::{format_benchmarks, format_results, Format};
+
+const USAGE: &str = "\
+Usage: aoc run <day|all> [input-file|-] [--format table|json] [--check]
+       aoc bench <day|all> [input-file|-] [--format table|json] [--iterations <count>]";
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+fn main() {
+    let mut format = Format::Table;
+    let mut check = false;
+    let mut iterations = DEFAULT_ITERATIONS;
+    let mut args: Vec<String> = Vec::new();
+    let mut all_args = env::args().skip(1);
+    while let Some(arg) = all_args.next() {
+        if arg == "--format" {
+            let value = all_args.next().unwrap_or_else(|| exit_with_error(USAGE));
+            format = value
+                .parse()
+                .unwrap_or_else(|error: String| exit_with_error(&error));
+        } else if arg == "--check" {
+            check = true;
+        } else if arg == "--iterations" {
+            iterations = all_args
+                .next()
+                .and_then(|value| value.parse().ok())
+                .filter(|count| *count > 0)
+                .unwrap_or_else(|| exit_with_error("The iteration count must be at least 1"));
+        } else {
+            args.push(arg);
+        }
+    }
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let inputs: Vec<(&Day, InputSource)> = match args.as_slice() {
+        [_, "all"] => DAYS
+            .iter()
+            .filter(|day| day.has_default_input())
+            .map(|day| (day, day.default_input()))
+            .collect(),
+        [_, day_number] => {
+            let day = find_day(day_number);
+            vec![(day, day.default_input())]
+        }
+        [_, _, _] if check => {
+            exit_with_error("The --check option can only be used with the default input files")
+        }
+        [_, day_number, path] => {
+            vec![(find_day(day_number), InputSource::from_arg(Some(path)))]
+        }
+        _ => exit_with_error(USAGE),
+    };
+
+    match args[0] {
+        "run" => run_days(&inputs, format, check),
+        "bench" if check => exit_with_error("The --check option can only be used with run"),
+        "bench" => bench_days(&inputs, format, iterations),
+        _ => exit_with_error(USAGE),
+    }
+}
+
+fn run_days(inputs: &[(&Day, InputSource)], format: Format, check: bool) {
+    let mut problems = Vec::new();
+    let results: Vec<(u8, Answers)> = inputs
+        .iter()
+        .map(|(day, input)| {
+            let answers = solve_day(day, input);
+            if check {
+                problems.extend(check_answers(day, &answers));
+            }
+            (day.number, answers)
+        })
+        .collect();
+    print!("{}", format_results(&results, format));
+
+    if check {
+        if !problems.is_empty() {
+            exit_with_error(&problems.join("\n"));
+        }
+        eprintln!("All answers match those recorded in answers.toml");
+    }
+}
+
+// Each day is benchmarked in turn, rather than interleaved, so that the timings for one day
+// aren't affected by the caches having been populated by another.
+fn bench_days(inputs: &[(&Day, InputSource)], format: Format, iterations: usize) {
+    let benchmarks: Vec<(u8, Benchmark)> = inputs
+        .iter()
+        .map(|(day, input)| {
+            let benchmark = input
+                .read_and_parse(|input| (day.benchmark)(input, iterations))
+                .unwrap_or_else(|error| {
+                    exit_with_error(&format!("Day {:02}: {error}", day.number))
+                });
+            (day.number, benchmark)
+        })
+        .collect();
+    print!("{}", format_benchmarks(&benchmarks, format));
+}
+
+fn find_day(day_number: &str) -> &'static Day {
+    let number = day_number
+        .parse::<u8>()
+        .unwrap_or_else(|_| exit_with_error(&format!("Invalid day number: {day_number}")));
+    DAYS.iter()
+        .find(|day| day.number == number)
+        .unwrap_or_else(|| exit_with_error(&format!("No solution exists for day {number}")))
+}
+
+fn solve_day(day: &Day, input: &InputSource) -> Answers {
+    input
+        .read_and_parse(day.solve)
+        .unwrap_or_else(|error| exit_with_error(&format!("Day {:02}: {error}", day.number)))
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
@@ -0,0 +1,274 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::bench::{Benchmark, Stats};
+use crate::days::{Answer, Answers};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "Unknown output format: {s} (expected table or json)"
+            )),
+        }
+    }
+}
+
+pub fn format_results(results: &[(u8, Answers)], format: Format) -> String {
+    match format {
+        Format::Table => results_table(results),
+        Format::Json => results_json(results),
+    }
+}
+
+// Answers that span several lines (such as day 13's decoded message) continue on
+// subsequent rows of the table, within the same column.
+fn results_table(results: &[(u8, Answers)]) -> String {
+    let part_one_width = results
+        .iter()
+        .flat_map(|(_, answers)| answers.part_one.value.lines())
+        .chain(["Part 1"])
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+
+    let mut table = format!("Day  {:part_one_width$}  Part 2\n", "Part 1");
+    for (day_number, answers) in results {
+        let part_one_lines: Vec<&str> = answers.part_one.value.lines().collect();
+        let part_two_lines: Vec<&str> = answers.part_two.value.lines().collect();
+        let total_lines = part_one_lines.len().max(part_two_lines.len());
+
+        for line_number in 0..total_lines {
+            let day_column = if line_number == 0 {
+                format!("{day_number:02}")
+            } else {
+                String::new()
+            };
+            let part_one = part_one_lines.get(line_number).unwrap_or(&"");
+            let part_two = part_two_lines.get(line_number).unwrap_or(&"");
+            let row = format!("{day_column:3}  {part_one:part_one_width$}  {part_two}");
+            table.push_str(row.trim_end());
+            table.push('\n');
+        }
+    }
+    table
+}
+
+// The field names here form a stable format that is consumed by other tools, so
+// shouldn't be changed. Answers are always strings, since not all are numeric.
+#[derive(Serialize)]
+struct DayJson<'a> {
+    day: u8,
+    input: &'a str,
+    part_one: PartJson<'a>,
+    part_two: PartJson<'a>,
+}
+
+#[derive(Serialize)]
+struct PartJson<'a> {
+    answer: &'a str,
+    elapsed_ms: f64,
+}
+
+impl<'a> From<&'a Answer> for PartJson<'a> {
+    fn from(answer: &'a Answer) -> Self {
+        Self {
+            answer: &answer.value,
+            elapsed_ms: answer.elapsed.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+fn results_json(results: &[(u8, Answers)]) -> String {
+    let days: Vec<DayJson> = results
+        .iter()
+        .map(|(day_number, answers)| DayJson {
+            day: *day_number,
+            input: &answers.input_summary,
+            part_one: PartJson::from(&answers.part_one),
+            part_two: PartJson::from(&answers.part_two),
+        })
+        .collect();
+    let mut json = serde_json::to_string_pretty(&days).expect("Serialising results cannot fail");
+    json.push('\n');
+    json
+}
+
+pub fn format_benchmarks(benchmarks: &[(u8, Benchmark)], format: Format) -> String {
+    match format {
+        Format::Table => benchmarks_table(benchmarks),
+        Format::Json => benchmarks_json(benchmarks),
+    }
+}
+
+fn benchmarks_table(benchmarks: &[(u8, Benchmark)]) -> String {
+    let mut table = format!(
+        "Day  Stage   {:>10}  {:>10}  {:>10}\n",
+        "Min", "Median", "Max"
+    );
+    for (day_number, benchmark) in benchmarks {
+        let stages = [
+            ("Parse", &benchmark.parse),
+            ("Part 1", &benchmark.part_one),
+            ("Part 2", &benchmark.part_two),
+        ];
+        for (stage_number, (stage, stats)) in stages.into_iter().enumerate() {
+            let day_column = if stage_number == 0 {
+                format!("{day_number:02}")
+            } else {
+                String::new()
+            };
+            let row = format!(
+                "{day_column:3}  {stage:6}  {:>10}  {:>10}  {:>10}\n",
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max)
+            );
+            table.push_str(&row);
+        }
+    }
+    table
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.3?}")
+}
+
+#[derive(Serialize)]
+struct BenchmarkJson {
+    day: u8,
+    iterations: usize,
+    parse: StatsJson,
+    part_one: StatsJson,
+    part_two: StatsJson,
+}
+
+#[derive(Serialize)]
+struct StatsJson {
+    #[serde(rename = "min_ms")]
+    min: f64,
+    #[serde(rename = "median_ms")]
+    median: f64,
+    #[serde(rename = "max_ms")]
+    max: f64,
+}
+
+impl From<&Stats> for StatsJson {
+    fn from(stats: &Stats) -> Self {
+        Self {
+            min: stats.min.as_secs_f64() * 1000.0,
+            median: stats.median.as_secs_f64() * 1000.0,
+            max: stats.max.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+fn benchmarks_json(benchmarks: &[(u8, Benchmark)]) -> String {
+    let days: Vec<BenchmarkJson> = benchmarks
+        .iter()
+        .map(|(day_number, benchmark)| BenchmarkJson {
+            day: *day_number,
+            iterations: benchmark.iterations,
+            parse: StatsJson::from(&benchmark.parse),
+            part_one: StatsJson::from(&benchmark.part_one),
+            part_two: StatsJson::from(&benchmark.part_two),
+        })
+        .collect();
+    let mut json = serde_json::to_string_pretty(&days).expect("Serialising benchmarks cannot fail");
+    json.push('\n');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn answers(part_one: &str, part_two: &str) -> Answers {
+        Answers {
+            input_summary: String::from("3 things"),
+            part_one: Answer {
+                value: part_one.to_string(),
+                elapsed: Duration::from_micros(1500),
+            },
+            part_two: Answer {
+                value: part_two.to_string(),
+                elapsed: Duration::from_millis(2),
+            },
+        }
+    }
+
+    #[test]
+    fn results_table_multi_line_answers() {
+        let results = [
+            (1, answers("7", "5")),
+            (13, answers("17", "#####\n#   #\n#####")),
+        ];
+        let expected = "\
+Day  Part 1  Part 2
+01   7       5
+13   17      #####
+             #   #
+             #####
+";
+        assert_eq!(results_table(&results), expected);
+    }
+
+    #[test]
+    fn results_json_object_per_day() {
+        let results = [(1, answers("7", "#\n#"))];
+        let expected = r##"[
+  {
+    "day": 1,
+    "input": "3 things",
+    "part_one": {
+      "answer": "7",
+      "elapsed_ms": 1.5
+    },
+    "part_two": {
+      "answer": "#\n#",
+      "elapsed_ms": 2.0
+    }
+  }
+]
+"##;
+        assert_eq!(results_json(&results), expected);
+    }
+
+    #[test]
+    fn benchmarks_table_row_per_stage() {
+        let stats = |millis| Stats {
+            min: Duration::from_millis(millis),
+            median: Duration::from_millis(millis * 2),
+            max: Duration::from_millis(millis * 3),
+        };
+        let benchmarks = [(
+            15,
+            Benchmark {
+                iterations: 10,
+                parse: stats(1),
+                part_one: stats(10),
+                part_two: stats(100),
+            },
+        )];
+        let expected = "\
+Day  Stage          Min      Median         Max
+15   Parse      1.000ms     2.000ms     3.000ms
+     Part 1    10.000ms    20.000ms    30.000ms
+     Part 2   100.000ms   200.000ms   300.000ms
+";
+        assert_eq!(benchmarks_table(&benchmarks), expected);
+    }
+}
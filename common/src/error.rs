@@ -0,0 +1,216 @@
+use std::error;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::InputSource;
+
+/// An error encountered whilst reading or parsing puzzle input.
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        input: InputSource,
+        source: io::Error,
+    },
+    Parse(ParseError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { input, source } => write!(f, "Error reading {input}: {source}"),
+            Error::Parse(parse_error) => parse_error.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(parse_error) => Some(parse_error),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(parse_error: ParseError) -> Self {
+        Error::Parse(parse_error)
+    }
+}
+
+/// Invalid puzzle input, along with its location and a description of what was expected instead.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub path: Option<PathBuf>,
+    // Both the line and column are 1-based, and the column is counted in characters.
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error for the invalid `text`, which must be a slice of `input` (as returned by
+    /// methods such as `str::lines` or `str::split_once`), so that its location can be determined.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `text` is not a slice of `input`.
+    #[must_use]
+    pub fn at(input: &str, text: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = location_of(input, text);
+        Self {
+            path: None,
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Converts an error whose location is relative to `fragment`, into one relative to `input`.
+    /// This allows errors from parsing a single line (such as those from a `FromStr` impl) to
+    /// report their location within the input as a whole.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragment` is not a slice of `input`.
+    #[must_use]
+    pub fn within(self, input: &str, fragment: &str) -> Self {
+        let (fragment_line, fragment_column) = location_of(input, fragment);
+        let column = if self.line == 1 {
+            fragment_column + self.column - 1
+        } else {
+            self.column
+        };
+        Self {
+            line: fragment_line + self.line - 1,
+            column,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_path(self, path: impl AsRef<Path>) -> Self {
+        Self {
+            path: Some(path.as_ref().to_path_buf()),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+        write!(
+            f,
+            "{}:{}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found {:?}", self.text)
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parses `text` (which must be a slice of `input`) using its `FromStr` impl, returning a
+/// located `ParseError` describing what was `expected` if it's invalid.
+///
+/// # Errors
+///
+/// Returns an error if `text` cannot be parsed as a `T`.
+pub fn parse_value<T: FromStr>(
+    input: &str,
+    text: &str,
+    expected: impl Into<String>,
+) -> Result<T, ParseError> {
+    text.parse::<T>()
+        .map_err(|_| ParseError::at(input, text, expected))
+}
+
+/// Parses each line of `input` using `T`'s `FromStr` impl, adjusting the location of any errors
+/// so that they're relative to the input as a whole, rather than the individual line.
+///
+/// # Errors
+///
+/// Returns an error if any of the lines cannot be parsed as a `T`.
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| line.parse::<T>().map_err(|error| error.within(input, line)))
+        .collect()
+}
+
+// Returns the 1-based line and column at which `text` starts within `input`.
+fn location_of(input: &str, text: &str) -> (usize, usize) {
+    let offset = (text.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|offset| offset + text.len() <= input.len())
+        .expect("Text must be a slice of the input");
+    let preceding = &input[..offset];
+    let line = preceding.matches('\n').count() + 1;
+    let line_start = preceding.rfind('\n').map_or(0, |index| index + 1);
+    let column = preceding[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_location() {
+        let input = "123\n4x6\n789";
+        let text = &input[5..6];
+        let error = ParseError::at(input, text, "a digit");
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "x");
+        assert_eq!(error.to_string(), r#"2:2: expected a digit, found "x""#);
+    }
+
+    #[test]
+    fn parse_error_within_fragment() {
+        let input = "forward 5\ndown x";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::at(line, &line[5..], "an amount").within(input, line);
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(
+            error.with_path("input.txt").to_string(),
+            r#"input.txt:2:6: expected an amount, found "x""#
+        );
+    }
+
+    #[test]
+    fn parse_error_missing_text() {
+        let input = "abc";
+        let error = ParseError::at(input, &input[3..], "a number");
+        assert_eq!((error.line, error.column), (1, 4));
+        assert_eq!(error.to_string(), "1:4: expected a number, found nothing");
+    }
+
+    #[test]
+    fn parse_lines_locates_errors() {
+        let error = parse_lines::<Number>("1\n2\n 3").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, " 3");
+    }
+
+    #[derive(Debug)]
+    struct Number;
+
+    impl FromStr for Number {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            parse_value::<u8>(s, s, "a number").map(|_| Self)
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::env;
+use std::fmt::{self, Display};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::{Error, ParseError};
+
+/// Where puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Determines the input source from the first command line argument, which is either the
+    /// path of the input file, or `-` (or omitted entirely) to read from stdin.
+    #[must_use]
+    pub fn from_args() -> Self {
+        Self::from_arg(env::args().nth(1).as_deref())
+    }
+
+    #[must_use]
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None | Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Reads the entire input into a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input cannot be read, or is not valid UTF-8.
+    pub fn read(&self) -> Result<String, Error> {
+        let result = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+        result.map_err(|source| self.io_error(source))
+    }
+
+    /// Reads the entire input, and parses it using `parse`. Any parse error is annotated with
+    /// the path of the input file, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input cannot be read, or if its contents are invalid.
+    pub fn read_and_parse<T>(
+        &self,
+        parse: impl FnOnce(&str) -> Result<T, ParseError>,
+    ) -> Result<T, Error> {
+        let input = self.read()?;
+        parse(&input).map_err(|error| self.parse_error(error))
+    }
+
+    /// Reads the input one line at a time (rather than all at once), parsing each line using
+    /// `parse`. This allows inputs that are too large to fit in memory to be processed. Any
+    /// parse error is located at the line it occurred on, and annotated with the path of the
+    /// input file, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input cannot be opened. Errors reading or parsing each line
+    /// are returned by the iterator instead.
+    pub fn read_and_parse_lines<T>(
+        &self,
+        parse: impl Fn(&str) -> Result<T, ParseError>,
+    ) -> Result<impl Iterator<Item = Result<T, Error>>, Error> {
+        let reader: Box<dyn BufRead> = match self {
+            InputSource::File(path) => Box::new(BufReader::new(
+                File::open(path).map_err(|source| self.io_error(source))?,
+            )),
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+        };
+        let input = self.clone();
+        Ok(reader.lines().enumerate().map(move |(index, line)| {
+            let line = line.map_err(|source| input.io_error(source))?;
+            parse(&line).map_err(|error| {
+                input.parse_error(ParseError {
+                    line: error.line + index,
+                    ..error
+                })
+            })
+        }))
+    }
+
+    fn io_error(&self, source: io::Error) -> Error {
+        Error::Io {
+            input: self.clone(),
+            source,
+        }
+    }
+
+    fn parse_error(&self, error: ParseError) -> Error {
+        match self {
+            InputSource::File(path) => Error::Parse(error.with_path(path)),
+            InputSource::Stdin => Error::Parse(error),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => path.display().fmt(f),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+impl From<PathBuf> for InputSource {
+    fn from(path: PathBuf) -> Self {
+        InputSource::File(path)
+    }
+}
+
+impl From<&Path> for InputSource {
+    fn from(path: &Path) -> Self {
+        InputSource::File(path.to_path_buf())
+    }
+}
+
+/// Reads the puzzle input (from either a file or stdin), and parses it using `parse`.
+///
+/// # Errors
+///
+/// Returns an error if the input cannot be read, or if its contents are invalid.
+pub fn read_and_parse<T>(
+    input: impl Into<InputSource>,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<T, Error> {
+    input.into().read_and_parse(parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_source_from_arg() {
+        assert_eq!(InputSource::from_arg(None), InputSource::Stdin);
+        assert_eq!(InputSource::from_arg(Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("input.txt")),
+            InputSource::File(PathBuf::from("input.txt"))
+        );
+    }
+
+    #[test]
+    fn read_and_parse_lines_locates_errors() {
+        let path = env::temp_dir().join("aoc-common-read-and-parse-lines.txt");
+        fs::write(&path, "1\n2\nx\n4").unwrap();
+        let input = InputSource::from(path.as_path());
+        let parsed: Vec<Result<u8, Error>> = input
+            .read_and_parse_lines(|line| crate::parse_value(line, line, "a number"))
+            .unwrap()
+            .collect();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(parsed.len(), 4);
+        assert_eq!(parsed[1].as_ref().unwrap(), &2);
+        assert_eq!(
+            parsed[2].as_ref().unwrap_err().to_string(),
+            format!(r#"{}:3:1: expected a number, found "x""#, path.display())
+        );
+    }
+}
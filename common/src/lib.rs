@@ -0,0 +1,50 @@
+// Functionality shared between the solutions for each day.
+
+#![warn(unused_crate_dependencies)]
+#![warn(clippy::pedantic)]
+
+mod error;
+mod input;
+mod parse;
+mod render;
+
+use std::fmt::Display;
+
+pub use error::{parse_lines, parse_value, Error, ParseError};
+pub use input::{read_and_parse, InputSource};
+pub use parse::{
+    comma_separated, grid_points, parse_comma_separated, parse_digit_grid, parse_grid, sections,
+};
+pub use render::{render_cells, render_grid};
+
+/// A solution to one day's puzzle, split into parsing the input and solving each part.
+pub trait Solution {
+    /// The day of the month (1-25) that the puzzle is for.
+    const DAY: u8;
+
+    /// The parsed puzzle input, which is shared between both parts.
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    /// Parses the raw puzzle input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the puzzle input is invalid.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// A short description of the parsed input for reporting purposes, eg "2000 depths".
+    fn summarise_input(input: &Self::Input) -> String;
+
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+}
+
+/// Formats an answer that arbitrary input may not have (such as when a puzzle has no solution),
+/// as "none" if there isn't one.
+#[must_use]
+pub fn optional_answer(answer: Option<impl Display>) -> String {
+    answer.map_or_else(|| String::from("none"), |answer| answer.to_string())
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::{parse_value, ParseError};
+
+/// Splits `input` into the sections separated by blank lines, such as a list of numbers to
+/// draw followed by several bingo cards. Each section is a slice of `input`.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    input.split_terminator("\n\n")
+}
+
+/// Splits a single line of comma separated values (eg "3,4,3,1,2") into the individual values,
+/// each of which is a slice of `input`.
+pub fn comma_separated(input: &str) -> impl Iterator<Item = &str> {
+    input.trim().split(',')
+}
+
+/// Parses a single line of comma separated values (eg "16,1,2,0,4"), returning a located
+/// `ParseError` describing what was `expected` if any of them are invalid.
+///
+/// # Errors
+///
+/// Returns an error if any of the values cannot be parsed as a `T`.
+pub fn parse_comma_separated<T: FromStr>(
+    input: &str,
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    comma_separated(input)
+        .map(|value| parse_value(input, value, expected))
+        .collect()
+}
+
+/// Parses a rectangular grid of characters (one row per line), using `parse_cell` to convert
+/// each character, which should return `None` if the character is invalid.
+///
+/// # Errors
+///
+/// Returns an error describing what was `expected` if any of the characters are invalid.
+pub fn parse_grid<T>(
+    input: &str,
+    expected: &str,
+    parse_cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(index, char)| {
+                    parse_cell(char).ok_or_else(|| {
+                        let text = &line[index..index + char.len_utf8()];
+                        ParseError::at(input, text, expected)
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Parses a grid of single digits (eg "2199943210\n3987894921"), one row per line.
+///
+/// # Errors
+///
+/// Returns an error describing what was `expected` if any of the characters are not digits.
+pub fn parse_digit_grid(input: &str, expected: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parse_grid(input, expected, |char| char.to_digit(10))
+}
+
+/// Converts a grid into a map of `(row, column)` coordinates to the value at that location.
+#[must_use]
+#[allow(clippy::cast_possible_wrap)]
+pub fn grid_points<T>(grid: Vec<Vec<T>>) -> HashMap<(i64, i64), T> {
+    grid.into_iter()
+        .enumerate()
+        .flat_map(|(row_num, row)| {
+            row.into_iter()
+                .enumerate()
+                .map(move |(col_num, value)| ((row_num as i64, col_num as i64), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_split_on_blank_lines() {
+        let input = "1,2,3\n\n4 5\n6 7\n\n8 9\n";
+        assert_eq!(
+            sections(input).collect::<Vec<_>>(),
+            ["1,2,3", "4 5\n6 7", "8 9\n"]
+        );
+    }
+
+    #[test]
+    fn parse_comma_separated_numbers() {
+        assert_eq!(
+            parse_comma_separated::<u8>("3,4,3,1,2\n", "a number"),
+            Ok(vec![3, 4, 3, 1, 2])
+        );
+        let error = parse_comma_separated::<u8>("3,4,x,1", "a number").unwrap_err();
+        assert_eq!(error.to_string(), r#"1:5: expected a number, found "x""#);
+    }
+
+    #[test]
+    fn parse_digit_grid_rows() {
+        assert_eq!(
+            parse_digit_grid("123\n456\n", "a digit"),
+            Ok(vec![vec![1, 2, 3], vec![4, 5, 6]])
+        );
+        let error = parse_digit_grid("123\n4.6", "a digit").unwrap_err();
+        assert_eq!(error.to_string(), r#"2:2: expected a digit, found ".""#);
+    }
+
+    #[test]
+    fn grid_points_by_row_and_column() {
+        let points = grid_points(vec![vec!['a', 'b'], vec!['c', 'd']]);
+        assert_eq!(points.len(), 4);
+        assert_eq!(points[&(0, 1)], 'b');
+        assert_eq!(points[&(1, 0)], 'c');
+    }
+}
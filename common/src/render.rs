@@ -0,0 +1,57 @@
+/// Renders a grid as text, one line per row, using `#` for lit cells and `unlit` for the
+/// others. The rows and columns are passed to `is_lit` for each cell in turn.
+pub fn render_grid<R, C>(
+    rows: impl IntoIterator<Item = R>,
+    columns: impl IntoIterator<Item = C> + Clone,
+    is_lit: impl Fn(&R, &C) -> bool,
+    unlit: char,
+) -> String {
+    render_cells(
+        rows,
+        columns,
+        move |row, column| {
+            if is_lit(row, column) {
+                '#'
+            } else {
+                unlit
+            }
+        },
+    )
+}
+
+/// Renders a grid as text, one line per row, using the character returned by `cell` for each.
+pub fn render_cells<R, C>(
+    rows: impl IntoIterator<Item = R>,
+    columns: impl IntoIterator<Item = C> + Clone,
+    cell: impl Fn(&R, &C) -> char,
+) -> String {
+    rows.into_iter()
+        .map(move |row| {
+            columns
+                .clone()
+                .into_iter()
+                .map(|column| cell(&row, &column))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_grid_rows() {
+        let rendered = render_grid(0..2, -1..=1, |row, column| row + column == 1, '.');
+        assert_eq!(rendered, "..#\n.#.");
+    }
+
+    #[test]
+    fn render_cells_rows() {
+        let rendered = render_cells(["ab", "cd"], 0..2, |row, column| {
+            row.chars().nth(*column).unwrap()
+        });
+        assert_eq!(rendered, "ab\ncd");
+    }
+}